@@ -2,7 +2,7 @@
 
 #[ink::contract]
 mod transcipt {
-    use ink::storage::Mapping;
+    use ink::storage::{Lazy, Mapping};
     use ink::prelude::string::String;
     use ink::prelude::vec::Vec;
 
@@ -10,31 +10,1083 @@ mod transcipt {
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum Error {
-        InvalidInput,
-        AccessNotAllowed
+        /// The caller is not allowed to perform this operation.
+        AccessNotAllowed,
+        /// The caller is not the teacher of the class.
+        NotClassTeacher,
+        /// Above a threshold of one admin approval the operation needs a proposal.
+        ProposalRequired,
+        /// The contract is paused and does not accept changes.
+        Paused,
+        /// The account already holds the role.
+        RoleAlreadyGranted,
+        /// The account does not hold the role.
+        RoleNotHeld,
+        /// The teaching assistant already assists the class.
+        AssistantAlreadyAdded,
+        /// The teaching assistant does not assist the class.
+        AssistantNotFound,
+        /// The admin role is always administered by admins.
+        AdminRoleFixed,
+        /// The account to remove does not hold the admin role.
+        NotAdmin,
+        /// The removal would leave fewer admins than required.
+        TooFewAdmins,
+        /// Other admins can not remove the owner.
+        OwnerNotRemovable,
+        /// Admins can only be recovered while fewer remain than proposals need.
+        EnoughAdmins,
+        /// Nobody asked for the removal of the admin.
+        RemovalNotProposed,
+        /// The account is not registered as a teacher.
+        TeacherNotRegistered,
+        /// The teacher still teaches classes that are not finalized.
+        TeacherHasClasses,
+        /// The account is not registered as a student.
+        StudentNotRegistered,
+        /// The student is archived and can not join classes.
+        StudentNotActive,
+        /// Students keep their role, they are archived with remove_student instead.
+        StudentRoleNotRevocable,
+        /// The term does not exist.
+        TermNotFound,
+        /// A term must end after it starts.
+        InvalidTermDates,
+        /// The term is closed to new classes and enrollments.
+        TermClosed,
+        /// Terms, classes and appeals only move forward in their lifecycle.
+        InvalidStatusTransition,
+        /// The class is not offered in the term.
+        ClassNotFound,
+        /// The class is already offered in the term.
+        ClassAlreadyExists,
+        /// Grading of the class started.
+        ClassNotOpen,
+        /// The grades of the class are final.
+        ClassFinalized,
+        /// The class is archived and its grades can no longer change.
+        ClassArchived,
+        /// Only archived students and classes can be purged.
+        NotArchived,
+        /// The student is not enrolled in the class.
+        StudentNotEnrolled,
+        /// The student is already enrolled in the class.
+        StudentAlreadyEnrolled,
+        /// The student is already on the waitlist of the class.
+        StudentAlreadyWaitlisted,
+        /// A capacity must allow at least one seat and all enrolled students.
+        InvalidCapacity,
+        /// The class has no assessment component with this name.
+        ComponentNotFound,
+        /// The class already has an assessment component with this name.
+        ComponentAlreadyExists,
+        /// Component weights must be positive and add up to at most 100%.
+        InvalidWeight,
+        /// Components must allow at least one point.
+        InvalidMaxPoints,
+        /// The points exceed the maximum of the component.
+        PointsExceedMax,
+        /// The student has no score at this position.
+        ScoreNotFound,
+        /// Amendments need a reason.
+        MissingReason,
+        /// Grading scales must be sorted by threshold, start at 100% or below and end at 0.
+        InvalidScale,
+        /// A grade appears more than once.
+        DuplicateGrade,
+        /// Grade points can not exceed the maximum.
+        InvalidGradePoints,
+        /// The appeal does not exist.
+        AppealNotFound,
+        /// The score is already under appeal.
+        AppealAlreadyOpen,
+        /// The teacher can still respond to the appeal.
+        AppealAwaitingResponse,
+        /// The account already has an active grant.
+        DuplicateGrant,
+        /// The expiry is not in the future.
+        InvalidExpiry,
+        /// The scope does not cover any class.
+        EmptyScope,
+        /// Students always keep access to their own grades.
+        CannotRevokeOwnAccess,
+        /// The account has no grant from the student.
+        GrantNotFound,
+        /// The access request does not exist.
+        RequestNotFound,
+        /// The access request was already approved, rejected or overridden.
+        RequestAlreadyDecided,
+        /// The grantee already waits for a decision of the student.
+        RequestAlreadyPending,
+        /// The student has too many access requests waiting for a decision.
+        TooManyPendingRequests,
+        /// The proposal does not exist.
+        ProposalNotFound,
+        /// The proposal was executed or expired.
+        ProposalClosed,
+        /// The admin already approved the proposal.
+        AlreadyApproved,
+        /// The threshold must be between one and the number of admins.
+        InvalidThreshold,
+        /// The code hash does not belong to uploaded contract code.
+        UpgradeFailed,
+        /// The storage is already at the current version.
+        NothingToMigrate,
+        /// No migration step exists for the stored version.
+        UnknownStorageVersion,
+        /// The contract is not paused.
+        NotPaused,
     }
 
     /// Specify the Transcipt result type.
     pub type Result<T> = core::result::Result<T, Error>;
 
+    /// Identifier of an academic term.
+    pub type TermId = u32;
 
-    
+    /// Lifecycle of an academic term.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum TermStatus {
+        Planned,
+        Active,
+        Closed,
+    }
+
+    /// An academic term (semester, quarter, ...) in which classes are offered.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Term {
+        pub id: TermId,
+        pub name: String,
+        pub start: Timestamp,
+        pub end: Timestamp,
+        pub status: TermStatus,
+    }
+
+    /// Percentages are expressed in hundredths of a percent, this is 100%.
+    pub const FULL_PERCENTAGE: u32 = 10_000;
+
+    /// Highest grade points a grade can carry, in hundredths of a grade point (10.00).
+    pub const MAX_GRADE_POINTS: u32 = 1_000;
+
+    /// Version of the storage layout written by this code, raised with every migration step.
+    /// Version 1 is the first layout that can be upgraded in place, earlier deployments had no upgrade message.
+    pub const STORAGE_VERSION: u32 = 2;
+
+    /// Number of admins that always remain in the registry.
+    pub const MIN_ADMINS: u32 = 1;
+
+    /// How long a proposal can collect approvals, in milliseconds of block time (7 days).
+    pub const PROPOSAL_WINDOW: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+    /// Number of access requests that can wait for the decision of one student.
+    /// Requests accounts make for themselves and requests access managers make for others are counted apart.
+    pub const MAX_PENDING_REQUESTS: u32 = 16;
+
+    /// How long the teacher has to respond to an appeal before an admin can resolve it without a response (14 days).
+    pub const APPEAL_RESPONSE_WINDOW: Timestamp = 14 * 24 * 60 * 60 * 1000;
+
+    /// Lifecycle of a class. Finalized classes have frozen grades that only admins can amend.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum ClassStatus {
+        /// Students can be enrolled and scores recorded.
+        Open,
+        /// Enrollment is closed, scores can still be recorded.
+        Grading,
+        /// Final grades are issued and frozen.
+        Finalized,
+        /// The class is kept as a read-only record.
+        Archived,
+    }
+
+    /// Standing of a registered student. Students who are not active keep their records but can not join classes.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum StudentStatus {
+        Active,
+        Inactive,
+        Graduated,
+        Withdrawn,
+    }
+
+    /// A class offered in a term.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ClassInfo {
+        pub teacher: AccountId,
+        pub students: Vec<AccountId>,
+        /// Credit hours earned by completing the class.
+        pub credits: u8,
+        pub status: ClassStatus,
+    }
+
+    /// Seats of a class. Classes without a capacity have unlimited seats.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SeatCount {
+        pub enrolled: u32,
+        pub capacity: Option<u32>,
+        pub waitlisted: u32,
+    }
+
+    /// Grade point average of a student, in hundredths of a grade point (350 = 3.50).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct GpaReport {
+        pub term_gpa: u32,
+        pub term_credits: u32,
+        pub cumulative_gpa: u32,
+        pub cumulative_credits: u32,
+    }
+
+    /// A correction of a recorded score, kept so that the previous value is never lost.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Amendment {
+        /// Position of the amended score in the student's scores for the class.
+        pub index: u32,
+        pub old_points: u32,
+        pub new_points: u32,
+        pub amended_by: AccountId,
+        pub timestamp: Timestamp,
+        pub reason: String,
+    }
+
+    /// Classes of a student covered by an access grant.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum AccessScope {
+        /// Every class, including the GPA over all of them.
+        All,
+        /// Only the listed class offerings.
+        Classes(Vec<(String, TermId)>),
+        /// Only the classes of one term.
+        Term(TermId),
+    }
+
+    impl AccessScope {
+        /// Whether the scope covers a class offering.
+        pub fn covers(&self, class_name: &str, term_id: TermId) -> bool {
+            match self {
+                AccessScope::All => true,
+                AccessScope::Classes(classes) => classes.iter().any(|(name, term)| name == class_name && *term == term_id),
+                AccessScope::Term(term) => *term == term_id,
+            }
+        }
+    }
+
+    /// Permission of an account to read a student's grades.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct AccessGrant {
+        pub grantee: AccountId,
+        /// Block timestamp from which the grant no longer applies, `None` for permanent grants.
+        pub expires_at: Option<Timestamp>,
+        pub scope: AccessScope,
+    }
+
+    impl AccessGrant {
+        /// Whether the grant still applies at the given block timestamp.
+        pub fn is_active(&self, now: Timestamp) -> bool {
+            self.expires_at.is_none_or(|expires_at| now < expires_at)
+        }
+    }
+
+    /// Identifier of a request for access to a student's grades.
+    pub type AccessRequestId = u32;
+
+    /// Progress of an access request.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum AccessRequestStatus {
+        /// Waiting for the student.
+        Pending,
+        /// The student approved and the grant is in place.
+        Approved,
+        /// The student declined.
+        Rejected,
+        /// An admin put the grant in place without the student's approval.
+        Overridden,
+    }
+
+    /// A request by staff or a third party to read a student's grades.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct AccessRequest {
+        pub id: AccessRequestId,
+        pub student: AccountId,
+        pub grantee: AccountId,
+        pub requested_by: AccountId,
+        pub scope: AccessScope,
+        pub expires_at: Option<Timestamp>,
+        pub status: AccessRequestStatus,
+        /// The student or the admin who decided the request.
+        pub decided_by: Option<AccountId>,
+    }
+
+    /// Identifier of a grade appeal.
+    pub type AppealId = u32;
+
+    /// Progress of a grade appeal.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum AppealStatus {
+        /// Waiting for the teacher of the class.
+        Open,
+        /// The teacher responded, waiting for an admin decision.
+        Responded,
+        /// An admin kept the score.
+        Upheld,
+        /// An admin changed the score.
+        Amended,
+    }
+
+    /// Decision of an admin on a grade appeal.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum AppealOutcome {
+        /// Keep the appealed score.
+        Uphold,
+        /// Replace the appealed score with new points.
+        Amend(u32),
+    }
+
+    /// A student's appeal of one of their scores.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Appeal {
+        pub id: AppealId,
+        pub student: AccountId,
+        pub class_name: String,
+        pub term_id: TermId,
+        /// Position of the appealed score in the student's scores for the class.
+        pub score_index: u32,
+        /// Hash of the student's reasoning, which is kept off-chain.
+        pub reason_hash: Hash,
+        /// Hash of the teacher's response, which is kept off-chain.
+        pub response_hash: Option<Hash>,
+        pub status: AppealStatus,
+        /// Points given by the admin when the appeal was amended.
+        pub new_points: Option<u32>,
+        pub opened_at: Timestamp,
+    }
+
+    /// One class on a student's transcript.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct TranscriptEntry {
+        pub class_name: String,
+        pub term_id: TermId,
+        pub teacher: AccountId,
+        pub credits: u8,
+        pub scores: Vec<Score>,
+        pub final_grade: Option<FinalGrade>,
+        /// Whether the class is finalized, otherwise the student is still enrolled.
+        pub completed: bool,
+    }
+
+    /// An assessment component of a class, e.g. Homework worth 30% of the final grade.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Component {
+        pub name: String,
+        /// Share of the final grade in whole percent.
+        pub weight: u8,
+        pub max_points: u32,
+    }
+
+    /// Points scored by a student on one assessment of a component.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Score {
+        pub component: String,
+        pub points: u32,
+    }
+
+    /// Official outcome of a class.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum Grade {
+        APlus,
+        A,
+        AMinus,
+        BPlus,
+        B,
+        BMinus,
+        CPlus,
+        C,
+        CMinus,
+        DPlus,
+        D,
+        DMinus,
+        F,
+        Pass,
+        Fail,
+    }
+
+    /// Grading scale as (minimum percentage, grade) thresholds from highest to lowest.
+    /// The lowest threshold must be 0 so that every percentage maps to a grade.
+    pub type GradingScale = Vec<(u32, Grade)>;
+
+    /// Final grade of a student in a class together with the percentage it was derived from.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct FinalGrade {
+        pub percentage: u32,
+        pub grade: Grade,
+    }
+
+    /// Roles that accounts can hold in the registry.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum Role {
+        Admin,
+        Teacher,
+        Student,
+        Registrar,
+        TeachingAssistant,
+        Auditor,
+        Advisor,
+    }
+
+    impl Role {
+        /// The role allowed to grant and revoke this role unless reconfigured.
+        /// Admins can always grant and revoke every role.
+        fn default_admin(self) -> Role {
+            match self {
+                Role::Admin | Role::Registrar | Role::Auditor => Role::Admin,
+                Role::Teacher | Role::Student | Role::Advisor => Role::Registrar,
+                Role::TeachingAssistant => Role::Teacher,
+            }
+        }
+    }
+
+    /// Capabilities checked by messages, each held by a fixed set of roles.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Capability {
+        /// Create and remove terms and classes and change their teacher.
+        ManageClasses,
+        /// Enroll and unenroll students.
+        ManageEnrollment,
+        /// Change the access list of any student.
+        ManageAccess,
+        /// Read the grades of any student.
+        ReadGrades,
+    }
+
+    /// Identifier of an admin proposal.
+    pub type ProposalId = u32;
+
+    /// Sensitive operations that need the approval of several admins.
+    /// New actions are only ever appended so that stored proposals keep decoding.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum AdminAction {
+        AddAdmin(AccountId),
+        RemoveAdmin(AccountId),
+        /// Archive a student as withdrawn, their records stay.
+        RemoveStudent(AccountId),
+        /// Archive a class, its grades stay.
+        RemoveClass(String, TermId),
+        /// Change the number of admin approvals a proposal needs.
+        SetThreshold(u32),
+        /// Replace the contract code, keeping its storage.
+        Upgrade(Hash),
+        /// Accept changes again after a pause.
+        Unpause,
+        /// Erase an archived student together with all of their records.
+        PurgeStudent(AccountId),
+        /// Erase an archived class together with all of its grades.
+        PurgeClass(String, TermId),
+        /// Offer ownership to another account, which has to accept it, the owner must approve.
+        TransferOwnership(AccountId),
+        /// Change the status of a student.
+        SetStudentStatus(AccountId, StudentStatus),
+        /// Unenroll a student from a class, which takes their grades.
+        UnenrollStudent(String, TermId, AccountId),
+    }
+
+    /// Progress of an admin proposal.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum ProposalStatus {
+        /// Collecting approvals.
+        Pending,
+        /// Enough admins approved and the action was carried out.
+        Executed,
+        /// The window closed before enough admins approved.
+        Expired,
+    }
+
+    /// An admin action waiting for or having received enough approvals.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Proposal {
+        pub id: ProposalId,
+        pub action: AdminAction,
+        pub proposer: AccountId,
+        /// Admins who approved, the proposer included.
+        pub approvals: Vec<AccountId>,
+        pub created_at: Timestamp,
+        pub expires_at: Timestamp,
+        pub status: ProposalStatus,
+    }
+
+    /// Which grades teachers and teaching assistants can read.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum TeacherReadPolicy {
+        /// The grades of every student.
+        AllStudents,
+        /// Only the grades in classes they teach or taught.
+        OwnClasses,
+    }
+
+    impl Capability {
+        fn roles(self) -> &'static [Role] {
+            match self {
+                Capability::ManageClasses | Capability::ManageEnrollment => &[Role::Admin, Role::Registrar],
+                Capability::ManageAccess => &[Role::Admin, Role::Registrar, Role::Teacher],
+                Capability::ReadGrades => &[Role::Admin, Role::Registrar, Role::Auditor, Role::Advisor],
+            }
+        }
+    }
+
+    /// Emitted when an admin registers a new teacher.
+    #[ink(event)]
+    pub struct TeacherAdded {
+        #[ink(topic)]
+        teacher: AccountId,
+    }
+
+    /// Emitted when a teacher is removed from the registry.
+    #[ink(event)]
+    pub struct TeacherRemoved {
+        #[ink(topic)]
+        teacher: AccountId,
+    }
+
+    /// Emitted when an admin registers a new student.
+    /// The student is implicitly granted access to their own grades.
+    #[ink(event)]
+    pub struct StudentAdded {
+        #[ink(topic)]
+        student: AccountId,
+    }
+
+    /// Emitted when a student and all of their records are purged from the registry.
+    #[ink(event)]
+    pub struct StudentRemoved {
+        #[ink(topic)]
+        student: AccountId,
+    }
+
+    /// Emitted when a student moves to a new status.
+    #[ink(event)]
+    pub struct StudentStatusChanged {
+        #[ink(topic)]
+        student: AccountId,
+        status: StudentStatus,
+    }
+
+    /// Emitted when a new admin is added.
+    #[ink(event)]
+    pub struct AdminAdded {
+        #[ink(topic)]
+        admin: AccountId,
+    }
+
+    /// Emitted when an admin is removed.
+    #[ink(event)]
+    pub struct AdminRemoved {
+        #[ink(topic)]
+        admin: AccountId,
+    }
+
+    /// Emitted when a new term is created.
+    #[ink(event)]
+    pub struct TermCreated {
+        #[ink(topic)]
+        term_id: TermId,
+        name: String,
+        start: Timestamp,
+        end: Timestamp,
+    }
+
+    /// Emitted when a term moves to a new status.
+    #[ink(event)]
+    pub struct TermStatusChanged {
+        #[ink(topic)]
+        term_id: TermId,
+        status: TermStatus,
+    }
+
+    /// Emitted when a class is created with its teacher and initial students.
+    #[ink(event)]
+    pub struct ClassCreated {
+        #[ink(topic)]
+        class_name: String,
+        #[ink(topic)]
+        term_id: TermId,
+        #[ink(topic)]
+        teacher: AccountId,
+        students: Vec<AccountId>,
+        credits: u8,
+        capacity: Option<u32>,
+    }
+
+    /// Emitted when a class and all of its grades are purged.
+    #[ink(event)]
+    pub struct ClassRemoved {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+    }
+
+    /// Emitted when a class moves to a new status.
+    #[ink(event)]
+    pub struct ClassStatusChanged {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        status: ClassStatus,
+    }
+
+    /// Emitted when a recorded score is corrected.
+    #[ink(event)]
+    pub struct ScoreAmended {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        student: AccountId,
+        index: u32,
+        old_points: u32,
+        new_points: u32,
+        #[ink(topic)]
+        amended_by: AccountId,
+        reason: String,
+    }
+
+    /// Emitted when a student appeals a score.
+    #[ink(event)]
+    pub struct AppealOpened {
+        #[ink(topic)]
+        appeal_id: AppealId,
+        #[ink(topic)]
+        student: AccountId,
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        score_index: u32,
+        reason_hash: Hash,
+    }
+
+    /// Emitted when the teacher of the class responds to an appeal.
+    #[ink(event)]
+    pub struct AppealResponded {
+        #[ink(topic)]
+        appeal_id: AppealId,
+        response_hash: Hash,
+    }
+
+    /// Emitted when an admin decides an appeal.
+    #[ink(event)]
+    pub struct AppealResolved {
+        #[ink(topic)]
+        appeal_id: AppealId,
+        status: AppealStatus,
+        new_points: Option<u32>,
+    }
+
+    /// Emitted when an assessment component is added to a class.
+    #[ink(event)]
+    pub struct ComponentAdded {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        component: String,
+        weight: u8,
+        max_points: u32,
+    }
+
+    /// Emitted when a teacher records a score for a student in a class.
+    #[ink(event)]
+    pub struct ScoreRecorded {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        student: AccountId,
+        component: String,
+        points: u32,
+    }
+
+    /// Emitted when the institution-wide grading scale changes.
+    #[ink(event)]
+    pub struct DefaultScaleChanged {
+        scale: GradingScale,
+    }
+
+    /// Emitted when a class gets its own grading scale.
+    #[ink(event)]
+    pub struct ClassScaleChanged {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        scale: GradingScale,
+    }
+
+    /// Emitted when the final grade of a student in a class is computed.
+    #[ink(event)]
+    pub struct FinalGradeRecorded {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        student: AccountId,
+        percentage: u32,
+        grade: Grade,
+    }
+
+    /// Emitted when the grade point table changes.
+    #[ink(event)]
+    pub struct GradePointsChanged {
+        grade_points: Vec<(Grade, u32)>,
+    }
+
+    /// Emitted when the teacher read policy changes.
+    #[ink(event)]
+    pub struct TeacherReadPolicyChanged {
+        policy: TeacherReadPolicy,
+    }
+
+    /// Emitted when an account is allowed to read a student's grades.
+    #[ink(event)]
+    pub struct AccessGranted {
+        #[ink(topic)]
+        student: AccountId,
+        #[ink(topic)]
+        grantee: AccountId,
+        expires_at: Option<Timestamp>,
+        scope: AccessScope,
+    }
+
+    /// Emitted when an account loses access to a student's grades.
+    #[ink(event)]
+    pub struct AccessRevoked {
+        #[ink(topic)]
+        student: AccountId,
+        #[ink(topic)]
+        grantee: AccountId,
+    }
+
+    /// Emitted when access to a student's grades is requested.
+    #[ink(event)]
+    pub struct AccessRequested {
+        #[ink(topic)]
+        request_id: AccessRequestId,
+        #[ink(topic)]
+        student: AccountId,
+        #[ink(topic)]
+        grantee: AccountId,
+        requested_by: AccountId,
+    }
+
+    /// Emitted when an access request is approved, rejected or overridden.
+    #[ink(event)]
+    pub struct AccessRequestDecided {
+        #[ink(topic)]
+        request_id: AccessRequestId,
+        #[ink(topic)]
+        decided_by: AccountId,
+        status: AccessRequestStatus,
+    }
+
+    /// Emitted when a student is enrolled in a class.
+    #[ink(event)]
+    pub struct StudentEnrolled {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        student: AccountId,
+    }
+
+    /// Emitted when a student is unenrolled from a class and their grades are dropped.
+    #[ink(event)]
+    pub struct StudentUnenrolled {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        student: AccountId,
+    }
+
+    /// Emitted when a student is put on the waitlist of a full class, at a position counted from 1.
+    #[ink(event)]
+    pub struct StudentWaitlisted {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        student: AccountId,
+        position: u32,
+    }
+
+    /// Emitted when a student leaves the waitlist of a class without being enrolled.
+    #[ink(event)]
+    pub struct StudentLeftWaitlist {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        student: AccountId,
+    }
+
+    /// Emitted when the capacity of a class is changed, no capacity means unlimited seats.
+    #[ink(event)]
+    pub struct ClassCapacityChanged {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        capacity: Option<u32>,
+    }
+
+    /// Emitted when a teaching assistant is assigned to a class.
+    #[ink(event)]
+    pub struct ClassAssistantAdded {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        assistant: AccountId,
+    }
+
+    /// Emitted when a teaching assistant no longer assists a class.
+    #[ink(event)]
+    pub struct ClassAssistantRemoved {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        assistant: AccountId,
+    }
+
+    /// Emitted when the teacher of a class is changed.
+    #[ink(event)]
+    pub struct TeacherChanged {
+        #[ink(topic)]
+        class_name: String,
+        term_id: TermId,
+        #[ink(topic)]
+        old_teacher: AccountId,
+        #[ink(topic)]
+        new_teacher: AccountId,
+    }
+
+    /// Emitted when a role without a dedicated event (registrar, teaching assistant, auditor) is granted.
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        role: Role,
+        #[ink(topic)]
+        account: AccountId,
+        sender: AccountId,
+    }
+
+    /// Emitted when a role without a dedicated event (registrar, teaching assistant, auditor) is revoked.
+    #[ink(event)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        role: Role,
+        #[ink(topic)]
+        account: AccountId,
+        sender: AccountId,
+    }
+
+    /// Emitted when the role allowed to grant and revoke a role changes.
+    #[ink(event)]
+    pub struct RoleAdminChanged {
+        #[ink(topic)]
+        role: Role,
+        previous_admin_role: Role,
+        new_admin_role: Role,
+    }
+
+    /// Emitted when an admin proposes a sensitive action.
+    #[ink(event)]
+    pub struct ProposalCreated {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        proposer: AccountId,
+        action: AdminAction,
+        expires_at: Timestamp,
+    }
+
+    /// Emitted when an admin approves a proposal.
+    #[ink(event)]
+    pub struct ProposalApproved {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        approver: AccountId,
+    }
+
+    /// Emitted when a proposal reaches its threshold and its action is carried out.
+    #[ink(event)]
+    pub struct ProposalExecuted {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+    }
+
+    /// Emitted when an admin asks for the removal of another admin.
+    #[ink(event)]
+    pub struct AdminRemovalProposed {
+        #[ink(topic)]
+        admin: AccountId,
+        #[ink(topic)]
+        proposed_by: AccountId,
+    }
+
+    /// Emitted when the owner offers ownership to another account.
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        previous_owner: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    /// Emitted when a new owner accepts ownership.
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    /// Emitted when an admin pauses the contract.
+    #[ink(event)]
+    pub struct Paused {
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    /// Emitted when the contract accepts changes again.
+    #[ink(event)]
+    pub struct Unpaused {
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    /// Emitted when the contract code is replaced.
+    #[ink(event)]
+    pub struct Upgraded {
+        #[ink(topic)]
+        code_hash: Hash,
+    }
+
+    /// Emitted when the storage is migrated to the layout of the current code.
+    #[ink(event)]
+    pub struct Migrated {
+        from_version: u32,
+        to_version: u32,
+    }
+
+    /// Emitted when the number of admin approvals a proposal needs changes.
+    #[ink(event)]
+    pub struct AdminThresholdChanged {
+        threshold: u32,
+    }
 
     /// Create storage for a Transcipt contract.
     #[ink(storage)]
+    #[allow(clippy::type_complexity)]
     pub struct Transcipt{
         
         //for each student define a list of people allowed to access the grade 
-        accessstudents: Mapping<AccountId, Vec<AccountId>>,
-        //store students, teachers, admins, and classes in lists 
-        students: Vec<AccountId>,
-        teachers: Vec<AccountId>,
-        admins: Vec<AccountId>,
-        class_list: Vec<String>,
-        //store a mapping from stduent and class to a vector of the students grades in that class
-        grades: Mapping<(AccountId, String), Vec<u8>>,
-        //store a mapping from a class to the teacher and a vector of students in that class
-        classes: Mapping<String,( AccountId, Vec<AccountId>)>,
+        accessstudents: Mapping<AccountId, Vec<AccessGrant>>,
+        //role membership of an account, storing its position in the role index
+        role_members: Mapping<(Role, AccountId), u32>,
+        //enumerable index of the members of each role
+        role_index: Mapping<(Role, u32), AccountId>,
+        //number of members in each role
+        role_count: Mapping<Role, u32>,
+        //role allowed to grant and revoke a role, when it differs from the default
+        role_admins: Mapping<Role, Role>,
+        //store terms by id and the number of terms created
+        terms: Mapping<TermId, Term>,
+        term_count: TermId,
+        //store class offerings (class name and term) in a list
+        class_list: Vec<(String, TermId)>,
+        //store the assessment components of each class offering
+        components: Mapping<(String, TermId), Vec<Component>>,
+        //store a mapping from stduent and class offering to a vector of the students scores in that class
+        grades: Mapping<(AccountId, String, TermId), Vec<Score>>,
+        //store every amendment of the scores of a student in a class offering
+        score_history: Mapping<(AccountId, String, TermId), Vec<Amendment>>,
+        //institution-wide grading scale and the classes that override it
+        default_scale: GradingScale,
+        class_scales: Mapping<(String, TermId), GradingScale>,
+        //store the final grade of a student in a class offering
+        final_grades: Mapping<(AccountId, String, TermId), FinalGrade>,
+        //store a mapping from a class offering to its teacher, students and credits
+        classes: Mapping<(String, TermId), ClassInfo>,
+        //store grade appeals by id, the number of appeals and the appeals of each student
+        appeals: Mapping<AppealId, Appeal>,
+        appeal_count: AppealId,
+        student_appeals: Mapping<AccountId, Vec<AppealId>>,
+        //store access requests by id, the number of requests and the requests concerning each student
+        access_requests: Mapping<AccessRequestId, AccessRequest>,
+        access_request_count: AccessRequestId,
+        student_access_requests: Mapping<AccountId, Vec<AccessRequestId>>,
+        //store the pending request of each student and grantee, and the number of pending requests of a student by whether the grantee asked themselves
+        pending_access_requests: Mapping<(AccountId, AccountId), AccessRequestId>,
+        pending_request_counts: Mapping<(AccountId, bool), u32>,
+        //store the class offerings each student is enrolled in
+        student_classes: Mapping<AccountId, Vec<(String, TermId)>>,
+        //grade points per grade in hundredths, grades without points do not count towards the gpa
+        grade_points: Vec<(Grade, u32)>,
+        //store every teacher a class offering had, the current one last
+        class_teachers: Mapping<(String, TermId), Vec<AccountId>>,
+        //which grades teachers can read
+        teacher_read_policy: TeacherReadPolicy,
+        //admin approvals needed for sensitive actions, above one they can only be done through proposals
+        admin_threshold: u32,
+        //store admin proposals by id and the number of proposals
+        proposals: Mapping<ProposalId, Proposal>,
+        proposal_count: ProposalId,
+        //admins waiting for a second admin to confirm their removal, with the admin who asked for it
+        admin_removals: Mapping<AccountId, AccountId>,
+        //the owner can restore admins and can not be removed by other admins
+        owner: AccountId,
+        //account the owner offered ownership to
+        pending_owner: Option<AccountId>,
+        //layout version of the stored data, fields added after version 1 are lazy or mappings
+        //so that the packed root of an upgraded contract still decodes before migrate is called
+        storage_version: u32,
+        //while paused only reads, unpausing, upgrades and owner recovery are allowed, unset means not paused
+        paused: Lazy<bool>,
+        //status of students who are not active
+        student_status: Mapping<AccountId, StudentStatus>,
+        //status archived classes had before they were archived
+        archived_classes: Mapping<(String, TermId), ClassStatus>,
+        //seat capacity of class offerings, classes without one have unlimited seats
+        class_capacities: Mapping<(String, TermId), u32>,
+        //students waiting for a seat in a class offering, in order
+        waitlists: Mapping<(String, TermId), Vec<AccountId>>,
+        //teaching assistants who record scores and read the grades of a class offering
+        class_assistants: Mapping<(String, TermId), Vec<AccountId>>,
+        //class offerings whose waitlist a student is on
+        student_waitlists: Mapping<AccountId, Vec<(String, TermId)>>,
+        //students who left a class offering with a score history, and the class offerings a student left with one
+        class_dropouts: Mapping<(String, TermId), Vec<AccountId>>,
+        student_dropouts: Mapping<AccountId, Vec<(String, TermId)>>,
     }
 
     impl Transcipt {
@@ -44,666 +1096,3766 @@ mod transcipt {
             
             //initiate default storage items
             let accessstudents = Mapping::default();
-            let teachers = Vec::default();
             let class_list = Vec::default();
             let grades = Mapping::default();
-            let students = Vec::default();
             let classes = Mapping::default(); 
-            let mut admins = Vec::default(); 
-            
-            //add contract caller as admin
-            admins.push(Self::env().caller());
 
-            Self {
+            let mut contract = Self {
                 accessstudents,
-                students,
-                teachers,
+                role_members: Mapping::default(),
+                role_index: Mapping::default(),
+                role_count: Mapping::default(),
+                role_admins: Mapping::default(),
+                terms: Mapping::default(),
+                term_count: 0,
+                components: Mapping::default(),
                 grades,
+                score_history: Mapping::default(),
+                appeals: Mapping::default(),
+                appeal_count: 0,
+                student_appeals: Mapping::default(),
+                access_requests: Mapping::default(),
+                access_request_count: 0,
+                student_access_requests: Mapping::default(),
+                pending_access_requests: Mapping::default(),
+                pending_request_counts: Mapping::default(),
+                student_classes: Mapping::default(),
+                grade_points: [
+                    (Grade::APlus, 400), (Grade::A, 400), (Grade::AMinus, 370),
+                    (Grade::BPlus, 330), (Grade::B, 300), (Grade::BMinus, 270),
+                    (Grade::CPlus, 230), (Grade::C, 200), (Grade::CMinus, 170),
+                    (Grade::DPlus, 130), (Grade::D, 100), (Grade::DMinus, 70),
+                    (Grade::F, 0),
+                ].to_vec(),
+                class_teachers: Mapping::default(),
+                teacher_read_policy: TeacherReadPolicy::OwnClasses,
+                admin_threshold: 1,
+                proposals: Mapping::default(),
+                proposal_count: 0,
+                admin_removals: Mapping::default(),
+                owner: Self::env().caller(),
+                pending_owner: None,
+                storage_version: STORAGE_VERSION,
+                paused: Lazy::default(),
+                student_status: Mapping::default(),
+                archived_classes: Mapping::default(),
+                class_capacities: Mapping::default(),
+                waitlists: Mapping::default(),
+                class_assistants: Mapping::default(),
+                student_waitlists: Mapping::default(),
+                class_dropouts: Mapping::default(),
+                student_dropouts: Mapping::default(),
+                default_scale: [(9000, Grade::A), (8000, Grade::B), (7000, Grade::C), (6000, Grade::D), (0, Grade::F)].to_vec(),
+                class_scales: Mapping::default(),
+                final_grades: Mapping::default(),
                 classes,
-                admins,
                 class_list
-            }
-            
+            };
+
+            //add contract caller as admin
+            contract.insert_member(Role::Admin, Self::env().caller());
+            contract
+
         }
         
 
         //adds teacher to storage 
         #[ink(message)]
         pub fn add_teacher(&mut self, teacher_id: AccountId) -> Result<()>{
-            //only the admin has access
-            if self.admins.contains(&Self::env().caller()) {
-                // only new teachers can be added 
-                if !self.teachers.contains(&teacher_id) {
-                    //adding teacher
-                    self.teachers.push(teacher_id);
-                    Ok(())
-            } else {
-                Err(Error::InvalidInput)
-            }
-            } else {
-                return Err(Error::AccessNotAllowed) 
-            }
+            self.grant_role(Role::Teacher, teacher_id)
         }
 
         //adding students to the system
         #[ink(message)]
         pub fn add_student(&mut self, student_id: AccountId) -> Result<()>{
-            //only admin has access
-            if self.admins.contains(&Self::env().caller()) {
-                //only new students can be added
-                if !self.students.contains(&student_id) {
-                    //add students and initate access list with student in it 
-                    self.students.push(student_id);
-                    self.accessstudents.insert(student_id, &[student_id].to_vec());
-                    Ok(())
-                } else {
-                    Err(Error::InvalidInput)
-                }
-            } else {
-                return Err(Error::AccessNotAllowed) 
-            }
+            self.grant_role(Role::Student, student_id)
         }
 
         //add admins 
         #[ink(message)]
         pub fn add_admins(&mut self, admin_id: AccountId) -> Result<()>{
-            //only admins can access
-            if self.admins.contains(&Self::env().caller()) {
-                //only new admins can be added
-                if !self.admins.contains(&admin_id) {
-                    self.admins.push(admin_id);
-                    Ok(())
-                } else {
-                    Err(Error::InvalidInput)
+            self.grant_role(Role::Admin, admin_id)
+        }
+
+        //grant a role to an account
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: Role, account: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only admins and holders of the role's admin role have access, new admins need a proposal above a threshold of one
+            if self.can_administer(Self::env().caller(), role) {
+                if role == Role::Admin && self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
                 }
+                self.grant(role, account)
             } else {
-                return Err(Error::AccessNotAllowed) 
+                Err(Error::AccessNotAllowed)
             }
         }
 
-        //adding classes to the system
+        //revoke a role from an account
         #[ink(message)]
-        pub fn add_classes(&mut self,class_name: String, teacher_id: AccountId, student_ids: Vec<AccountId>) -> Result<()>{
-            //only admins have access
-            if self.admins.contains(&Self::env().caller()) {
-                //teacher must be saved as teacher, students must be saved as students, the clast must be new 
-                if self.teachers.contains(&teacher_id) && student_ids.iter().all(|x| self.students.contains(x)) && !self.class_list.contains(&class_name) {
-                    //adding the class to the list of classes and save students and teacher in mapping
-                    self.classes.insert(&class_name, &(teacher_id, student_ids));
-                    self.class_list.push(class_name);
-                    Ok(())
+        pub fn revoke_role(&mut self, role: Role, account: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+            //admins are removed in two steps, students are archived and keep their role
+            if role == Role::Admin {
+                return self.remove_admins(account)
+            }
+            if role == Role::Student {
+                return Err(Error::StudentRoleNotRevocable)
+            }
+
+            //only admins and holders of the role's admin role have access
+            if self.can_administer(Self::env().caller(), role) {
+                //teachers must hand over their active classes first
+                if role == Role::Teacher && !self.active_classes_of(account).is_empty() {
+                    return Err(Error::TeacherHasClasses)
+                }
+                if self.is_member(role, account) {
+                    self.revoke(role, account)
+                } else if role == Role::Teacher {
+                    Err(Error::TeacherNotRegistered)
                 } else {
-                    Err(Error::InvalidInput)
+                    Err(Error::RoleNotHeld)
                 }
             } else {
-                return Err(Error::AccessNotAllowed) 
+                Err(Error::AccessNotAllowed)
             }
         }
 
-        //adding a score to a student in a class
+        //check whether an account holds a role
         #[ink(message)]
-        pub fn add_score(&mut self,class_name: String, student_id: AccountId, grade: u8) -> Result<()>{
+        pub fn has_role(&self, role: Role, account: AccountId) -> bool {
+            self.is_member(role, account)
+        }
 
-            //accessing class info
-            let class_info = if let Some(class_info) = self.classes.get(&class_name) { class_info } else { return Err(Error::InvalidInput)  };
-            let teacher = class_info.0;
-            let students = class_info.1;
-
-            //only teacher of the class can add and student must be stored as one 
-            if teacher == Self::env().caller() && students.contains(&student_id) {
-                //add grade to list of grades of student in that class
-                let mut current_grades = if let Some(current_grades) = self.grades.get((student_id, &class_name)) { current_grades } else { [].to_vec() };
-                current_grades.push(grade);
-                self.grades.insert((student_id, &class_name), &current_grades);
-                Ok(())
+        //list all accounts holding a role
+        #[ink(message)]
+        pub fn get_role_members(&self, role: Role) -> Vec<AccountId> {
+            self.members(role)
+        }
 
-            } else {
-                return Err(Error::AccessNotAllowed) 
-            }
+        //get the role allowed to grant and revoke a role
+        #[ink(message)]
+        pub fn get_role_admin(&self, role: Role) -> Role {
+            self.role_admins.get(role).unwrap_or(role.default_admin())
         }
-    
 
-        //adding any account to be able to access the grades of a specific student
+        //change the role allowed to grant and revoke a role
         #[ink(message)]
-        pub fn add_accessstudents(&mut self, student_id: AccountId, new_access_id: AccountId) -> Result<()> {
-            //only admins, teachers or the specific student specified in the input can change this 
-            if self.teachers.contains(&Self::env().caller()) || self.admins.contains(&Self::env().caller()) || Self::env().caller() == student_id {
-                //must be a new acount id 
-                if !self.accessstudents.get(student_id).unwrap().contains(&new_access_id) {
-                    //add new id to list 
-                    let mut current_access = self.accessstudents.get(student_id).unwrap_or_default();
-                    current_access.push(new_access_id);
-                    self.accessstudents.insert(student_id, &current_access);
-                    
+        pub fn set_role_admin(&mut self, role: Role, admin_role: Role) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only admins have access
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                //admins are always administered by admins
+                if role != Role::Admin {
+                    let previous_admin_role = self.get_role_admin(role);
+                    self.role_admins.insert(role, &admin_role);
+                    Self::env().emit_event(RoleAdminChanged { role, previous_admin_role, new_admin_role: admin_role });
                     Ok(())
                 } else {
-                    Err(Error::InvalidInput)
+                    Err(Error::AdminRoleFixed)
                 }
             } else {
-                return Err(Error::AccessNotAllowed) 
+                Err(Error::AccessNotAllowed)
             }
-            
         }
 
-        //access the grades of a student for a specific class
+        //adding a term to the system
         #[ink(message)]
-        pub fn access_grades(&self,class_name: String, student_id: AccountId) -> Result<Vec<u8>> {
-            //get all people who have access to the grades of the student
-            let has_access = self.accessstudents.get(student_id).unwrap_or_default();
-            //admins, teachers, and people on the allow list have access
-            if self.teachers.contains(&Self::env().caller()) || self.admins.contains(&Self::env().caller()) || has_access.contains(&Self::env().caller()) {
-                //get and return grades
-                let current_grades = self.grades.get((student_id, &class_name)).unwrap_or_default();
-                return Ok(current_grades)
+        pub fn add_term(&mut self, name: String, start: Timestamp, end: Timestamp) -> Result<TermId>{
+            self.ensure_not_paused()?;
+            //only class managers have access
+            if self.has_capability(Self::env().caller(), Capability::ManageClasses) {
+                //the term must end after it starts
+                if start < end {
+                    let id = self.term_count;
+                    self.terms.insert(id, &Term { id, name: name.clone(), start, end, status: TermStatus::Planned });
+                    self.term_count += 1;
+                    Self::env().emit_event(TermCreated { term_id: id, name, start, end });
+                    Ok(id)
+                } else {
+                    Err(Error::InvalidTermDates)
+                }
             } else {
-                Err(Error::AccessNotAllowed) 
+                Err(Error::AccessNotAllowed)
             }
-            
         }
 
-        //remove a person from the access list of a student
+        //move a term forward in its lifecycle
         #[ink(message)]
-        pub fn remove_accessstudents(&mut self, student_id: AccountId, remove_access_id: AccountId) -> Result<()> {
-            
-            if self.teachers.contains(&Self::env().caller()) || self.admins.contains(&Self::env().caller()) {
-
-                let mut current_access = self.accessstudents.get(student_id).unwrap_or_default();
-                if let Some(index) = current_access.iter().position(|x| *x == remove_access_id) {
-                    current_access.remove(index);
+        pub fn set_term_status(&mut self, term_id: TermId, status: TermStatus) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only class managers have access
+            if self.has_capability(Self::env().caller(), Capability::ManageClasses) {
+                let mut term = if let Some(term) = self.terms.get(term_id) { term } else { return Err(Error::TermNotFound) };
+                //terms can only move forward
+                if term.status < status {
+                    term.status = status;
+                    self.terms.insert(term_id, &term);
+                    Self::env().emit_event(TermStatusChanged { term_id, status });
+                    Ok(())
+                } else {
+                    Err(Error::InvalidStatusTransition)
                 }
-
-                self.accessstudents.insert(student_id, &current_access);
-                
-                Ok(())
             } else {
-                return Err(Error::AccessNotAllowed) 
+                Err(Error::AccessNotAllowed)
             }
-            
         }
 
+        //get a single term
         #[ink(message)]
-        pub fn remove_admins(&mut self, admin_id: AccountId) -> Result<()>{
-
-            if self.admins.contains(&Self::env().caller()) && self.admins.len() >= 2 {
-                if let Some(index) = self.admins.iter().position(|x| *x == admin_id) {
-                    self.admins.remove(index);
-                }
-                Ok(())
-            } else {
-                return Err(Error::AccessNotAllowed) 
-            }
+        pub fn get_term(&self, term_id: TermId) -> Option<Term> {
+            self.terms.get(term_id)
         }
 
+        //list all terms
         #[ink(message)]
-        pub fn remove_classes(&mut self,class_name: String) -> Result<()>{
-
-
-
-            if self.admins.contains(&Self::env().caller()) {
+        pub fn get_terms(&self) -> Vec<Term> {
+            (0..self.term_count).filter_map(|id| self.terms.get(id)).collect()
+        }
 
-                let class_info = self.classes.get(&class_name).unwrap();
-                let students = class_info.1;
+        //adding classes to the system, students beyond the capacity go to the waitlist in the given order
+        #[ink(message)]
+        pub fn add_classes(&mut self,class_name: String, term_id: TermId, teacher_id: AccountId, student_ids: Vec<AccountId>, credits: u8, capacity: Option<u32>) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only class managers have access
+            if self.has_capability(Self::env().caller(), Capability::ManageClasses) {
+                //teacher must be saved as teacher, students must be saved as students, the term must be open, the class must be new in the term
+                if !self.is_member(Role::Teacher, teacher_id) {
+                    Err(Error::TeacherNotRegistered)
+                } else if !student_ids.iter().all(|x| self.is_member(Role::Student, *x)) {
+                    Err(Error::StudentNotRegistered)
+                } else if !student_ids.iter().all(|x| self.is_active_student(*x)) {
+                    Err(Error::StudentNotActive)
+                } else if student_ids.iter().enumerate().any(|(index, x)| student_ids[..index].contains(x)) {
+                    Err(Error::StudentAlreadyEnrolled)
+                } else if !self.terms.contains(term_id) {
+                    Err(Error::TermNotFound)
+                } else if !self.is_term_open(term_id) {
+                    Err(Error::TermClosed)
+                } else if self.classes.contains((&class_name, term_id)) {
+                    Err(Error::ClassAlreadyExists)
+                } else if capacity == Some(0) {
+                    Err(Error::InvalidCapacity)
+                } else {
+                    //the first students up to the capacity get a seat
+                    let mut student_ids = student_ids;
+                    let waitlisted = capacity.map_or(Vec::new(), |capacity| student_ids.split_off(student_ids.len().min(capacity as usize)));
 
-                for student in students.iter() {
-                    self.grades.take((student, &class_name));
-                }
+                    //adding the class to the list of classes and save students and teacher in mapping
+                    self.classes.insert((&class_name, term_id), &ClassInfo { teacher: teacher_id, students: student_ids.clone(), credits, status: ClassStatus::Open });
+                    self.class_teachers.insert((&class_name, term_id), &[teacher_id].to_vec());
+                    if let Some(capacity) = capacity {
+                        self.class_capacities.insert((&class_name, term_id), &capacity);
+                    }
+                    self.class_list.push((class_name.clone(), term_id));
+                    for student in student_ids.iter() {
+                        self.add_student_class(*student, &class_name, term_id);
+                    }
+                    Self::env().emit_event(ClassCreated { class_name: class_name.clone(), term_id, teacher: teacher_id, students: student_ids, credits, capacity });
+                    for student in waitlisted.into_iter() {
+                        let position = self.join_waitlist(&class_name, term_id, student);
+                        Self::env().emit_event(StudentWaitlisted { class_name: class_name.clone(), term_id, student, position });
+                    }
+                    Ok(())
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
 
-                self.classes.take(&class_name);
-                
+        //adding an assessment component to a class
+        #[ink(message)]
+        pub fn add_component(&mut self, class_name: String, term_id: TermId, name: String, weight: u8, max_points: u32) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            //accessing class info
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            //only the teacher of the class and class managers can add components, until grading starts
+            if self.can_manage_class(Self::env().caller(), &class_info) {
+                if class_info.status != ClassStatus::Open {
+                    return Err(Error::ClassNotOpen)
+                }
+                let mut components = self.components.get((&class_name, term_id)).unwrap_or_default();
+                let total_weight: u32 = components.iter().map(|component| u32::from(component.weight)).sum();
+                //component names are unique, weights add up to at most 100% and every component can score points
+                if weight == 0 || total_weight + u32::from(weight) > 100 {
+                    Err(Error::InvalidWeight)
+                } else if max_points == 0 {
+                    Err(Error::InvalidMaxPoints)
+                } else if components.iter().any(|component| component.name == name) {
+                    Err(Error::ComponentAlreadyExists)
+                } else {
+                    components.push(Component { name: name.clone(), weight, max_points });
+                    self.components.insert((&class_name, term_id), &components);
+                    Self::env().emit_event(ComponentAdded { class_name, term_id, component: name, weight, max_points });
+                    Ok(())
+                }
+            } else {
+                Err(Error::NotClassTeacher)
+            }
+        }
+
+        //list the assessment components of a class
+        #[ink(message)]
+        pub fn get_components(&self, class_name: String, term_id: TermId) -> Vec<Component> {
+            self.components.get((&class_name, term_id)).unwrap_or_default()
+        }
 
-                if let Some(index) = self.class_list.iter().position(|x| *x == class_name) {
-                    self.class_list.remove(index);
+        //adding a score for an assessment component to a student in a class
+        #[ink(message)]
+        pub fn add_score(&mut self,class_name: String, term_id: TermId, student_id: AccountId, component: String, points: u32) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            //accessing class info
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+            let teacher = class_info.teacher;
+            let students = class_info.students;
+
+            //only teacher and teaching assistants of the class can add and student must be stored as one 
+            if teacher == Self::env().caller() || self.assists_class(Self::env().caller(), &class_name, term_id) {
+                //removed teachers keep no access to their old classes
+                if teacher == Self::env().caller() && !self.is_member(Role::Teacher, teacher) {
+                    return Err(Error::TeacherNotRegistered)
                 }
+                if !students.contains(&student_id) {
+                    return Err(Error::StudentNotEnrolled)
+                }
+                //finalized grades can only be amended, archived grades not at all
+                if class_info.status == ClassStatus::Archived {
+                    return Err(Error::ClassArchived)
+                }
+                if class_info.status == ClassStatus::Finalized {
+                    return Err(Error::ClassFinalized)
+                }
+
+                //the component must exist and the points must not exceed its maximum
+                let components = self.components.get((&class_name, term_id)).unwrap_or_default();
+                Self::check_points(&components, &component, points)?;
 
+                //add score to list of scores of student in that class
+                let mut current_grades = if let Some(current_grades) = self.grades.get((student_id, &class_name, term_id)) { current_grades } else { [].to_vec() };
+                current_grades.push(Score { component: component.clone(), points });
+                self.grades.insert((student_id, &class_name, term_id), &current_grades);
+                Self::env().emit_event(ScoreRecorded { class_name, term_id, student: student_id, component, points });
                 Ok(())
 
             } else {
-                return Err(Error::AccessNotAllowed) 
+                Err(Error::NotClassTeacher)
             }
         }
+    
 
+        //change the institution-wide grading scale
         #[ink(message)]
-        pub fn unenroll_student(&mut self,class_name: String, student_id: AccountId) -> Result<()>{
-            if self.admins.contains(&Self::env().caller()) {
+        pub fn set_default_scale(&mut self, scale: GradingScale) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only class managers have access
+            if self.has_capability(Self::env().caller(), Capability::ManageClasses) {
+                if Self::is_valid_scale(&scale) {
+                    self.default_scale = scale.clone();
+                    Self::env().emit_event(DefaultScaleChanged { scale });
+                    Ok(())
+                } else {
+                    Err(Error::InvalidScale)
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
 
-                let class_info = self.classes.get(&class_name).unwrap();
-                let mut students = class_info.1;
-                
+        //give a class its own grading scale
+        #[ink(message)]
+        pub fn set_class_scale(&mut self, class_name: String, term_id: TermId, scale: GradingScale) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            //accessing class info
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            //only the teacher of the class and class managers have access, until the class is finalized
+            if self.can_manage_class(Self::env().caller(), &class_info) {
+                if class_info.status == ClassStatus::Finalized {
+                    Err(Error::ClassFinalized)
+                } else if class_info.status == ClassStatus::Archived {
+                    Err(Error::ClassArchived)
+                } else if !Self::is_valid_scale(&scale) {
+                    Err(Error::InvalidScale)
+                } else {
+                    self.class_scales.insert((&class_name, term_id), &scale);
+                    Self::env().emit_event(ClassScaleChanged { class_name, term_id, scale });
+                    Ok(())
+                }
+            } else {
+                Err(Error::NotClassTeacher)
+            }
+        }
+
+        //get the grading scale used by a class
+        #[ink(message)]
+        pub fn get_grading_scale(&self, class_name: String, term_id: TermId) -> GradingScale {
+            self.class_scales.get((&class_name, term_id)).unwrap_or_else(|| self.default_scale.clone())
+        }
+
+        //compute and store the final grade of every student in a class
+        #[ink(message)]
+        pub fn compute_final_grades(&mut self, class_name: String, term_id: TermId) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            //accessing class info
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            //only the teacher of the class and class managers have access, until the class is finalized
+            if self.can_manage_class(Self::env().caller(), &class_info) {
+                if class_info.status == ClassStatus::Finalized {
+                    return Err(Error::ClassFinalized)
+                }
+                if class_info.status == ClassStatus::Archived {
+                    return Err(Error::ClassArchived)
+                }
+                for student in class_info.students.iter() {
+                    self.record_final_grade(&class_name, term_id, *student);
+                }
+                Ok(())
+            } else {
+                Err(Error::NotClassTeacher)
+            }
+        }
+
+        //close enrollment of a class so that it can be graded
+        #[ink(message)]
+        pub fn start_grading(&mut self, class_name: String, term_id: TermId) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            //accessing class info
+            let mut class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            //only the teacher of the class and class managers have access
+            if self.can_manage_class(Self::env().caller(), &class_info) {
+                if class_info.status == ClassStatus::Open {
+                    class_info.status = ClassStatus::Grading;
+                    self.classes.insert((&class_name, term_id), &class_info);
+                    Self::env().emit_event(ClassStatusChanged { class_name, term_id, status: ClassStatus::Grading });
+                    Ok(())
+                } else {
+                    Err(Error::InvalidStatusTransition)
+                }
+            } else {
+                Err(Error::NotClassTeacher)
+            }
+        }
+
+        //issue the final grades of a class and freeze them
+        #[ink(message)]
+        pub fn finalize_class(&mut self, class_name: String, term_id: TermId) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            //accessing class info
+            let mut class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            //only the teacher of the class and class managers have access
+            if self.can_manage_class(Self::env().caller(), &class_info) {
+                if class_info.status == ClassStatus::Grading {
+                    for student in class_info.students.iter() {
+                        self.record_final_grade(&class_name, term_id, *student);
+                    }
+                    class_info.status = ClassStatus::Finalized;
+                    self.classes.insert((&class_name, term_id), &class_info);
+                    Self::env().emit_event(ClassStatusChanged { class_name, term_id, status: ClassStatus::Finalized });
+                    Ok(())
+                } else {
+                    Err(Error::InvalidStatusTransition)
+                }
+            } else {
+                Err(Error::NotClassTeacher)
+            }
+        }
+
+        //correct a recorded score, once the class is finalized only admins can amend it
+        #[ink(message)]
+        pub fn amend_score(&mut self, class_name: String, term_id: TermId, student_id: AccountId, index: u32, points: u32, reason: String) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            //accessing class info
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            if class_info.status == ClassStatus::Archived {
+                Err(Error::ClassArchived)
+            } else if class_info.status == ClassStatus::Finalized {
+                if self.is_member(Role::Admin, Self::env().caller()) {
+                    self.amend(class_name, term_id, student_id, index, points, reason)
+                } else {
+                    Err(Error::AccessNotAllowed)
+                }
+            } else if class_info.teacher == Self::env().caller() {
+                if !self.is_member(Role::Teacher, class_info.teacher) {
+                    return Err(Error::TeacherNotRegistered)
+                }
+                self.amend(class_name, term_id, student_id, index, points, reason)
+            } else {
+                Err(Error::NotClassTeacher)
+            }
+        }
+
+        //appeal one of your own scores in a class
+        #[ink(message)]
+        pub fn open_appeal(&mut self, class_name: String, term_id: TermId, score_index: u32, reason_hash: Hash) -> Result<AppealId>{
+            self.ensure_not_paused()?;
+            let student = Self::env().caller();
+
+            //accessing class info
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            //only students of the class can appeal their own scores
+            if class_info.students.contains(&student) {
+                let scores = self.grades.get((student, &class_name, term_id)).unwrap_or_default();
+                let student_appeals = self.student_appeals.get(student).unwrap_or_default();
+                //the score must exist and must not be under appeal already
+                let pending = student_appeals.iter().filter_map(|id| self.appeals.get(id)).any(|appeal| {
+                    appeal.class_name == class_name && appeal.term_id == term_id && appeal.score_index == score_index
+                        && matches!(appeal.status, AppealStatus::Open | AppealStatus::Responded)
+                });
+                if (score_index as usize) >= scores.len() {
+                    Err(Error::ScoreNotFound)
+                } else if pending {
+                    Err(Error::AppealAlreadyOpen)
+                } else {
+                    let id = self.appeal_count;
+                    self.appeals.insert(id, &Appeal {
+                        id,
+                        student,
+                        class_name: class_name.clone(),
+                        term_id,
+                        score_index,
+                        reason_hash,
+                        response_hash: None,
+                        status: AppealStatus::Open,
+                        new_points: None,
+                        opened_at: Self::env().block_timestamp(),
+                    });
+                    self.appeal_count += 1;
+                    let mut student_appeals = student_appeals;
+                    student_appeals.push(id);
+                    self.student_appeals.insert(student, &student_appeals);
+                    Self::env().emit_event(AppealOpened { appeal_id: id, student, class_name, term_id, score_index, reason_hash });
+                    Ok(id)
+                }
+            } else {
+                Err(Error::StudentNotEnrolled)
+            }
+        }
+
+        //respond to an appeal as the teacher of the class
+        #[ink(message)]
+        pub fn respond_appeal(&mut self, appeal_id: AppealId, response_hash: Hash) -> Result<()>{
+            self.ensure_not_paused()?;
+            let mut appeal = if let Some(appeal) = self.appeals.get(appeal_id) { appeal } else { return Err(Error::AppealNotFound) };
+            let teacher = self.classes.get((&appeal.class_name, appeal.term_id)).map(|class_info| class_info.teacher);
+
+            //only the teacher of the class has access, removed teachers keep no access to their old classes
+            if teacher == Some(Self::env().caller()) {
+                if !self.is_member(Role::Teacher, Self::env().caller()) {
+                    return Err(Error::TeacherNotRegistered)
+                }
+                if appeal.status == AppealStatus::Open {
+                    appeal.response_hash = Some(response_hash);
+                    appeal.status = AppealStatus::Responded;
+                    self.appeals.insert(appeal_id, &appeal);
+                    Self::env().emit_event(AppealResponded { appeal_id, response_hash });
+                    Ok(())
+                } else {
+                    Err(Error::InvalidStatusTransition)
+                }
+            } else {
+                Err(Error::NotClassTeacher)
+            }
+        }
 
-                if self.students.contains(&student_id) && students.contains(&student_id) {
+        //decide an appeal after the teacher responded or let the response window pass, amending the score if it is not upheld
+        #[ink(message)]
+        pub fn resolve_appeal(&mut self, appeal_id: AppealId, outcome: AppealOutcome) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only admins have access
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                let mut appeal = if let Some(appeal) = self.appeals.get(appeal_id) { appeal } else { return Err(Error::AppealNotFound) };
+                if !matches!(appeal.status, AppealStatus::Open | AppealStatus::Responded) {
+                    return Err(Error::InvalidStatusTransition)
+                }
+                //the teacher responds first, unless they let the response window pass
+                if appeal.status == AppealStatus::Open && Self::env().block_timestamp() < appeal.opened_at + APPEAL_RESPONSE_WINDOW {
+                    return Err(Error::AppealAwaitingResponse)
+                }
 
-                    if let Some(index) = students.iter().position(|x| *x == student_id) {
-                        students.remove(index);
+                match outcome {
+                    AppealOutcome::Uphold => appeal.status = AppealStatus::Upheld,
+                    AppealOutcome::Amend(points) => {
+                        self.amend(appeal.class_name.clone(), appeal.term_id, appeal.student, appeal.score_index, points, ink::prelude::format!("appeal {}", appeal_id))?;
+                        appeal.status = AppealStatus::Amended;
+                        appeal.new_points = Some(points);
                     }
+                }
+                self.appeals.insert(appeal_id, &appeal);
+                Self::env().emit_event(AppealResolved { appeal_id, status: appeal.status, new_points: appeal.new_points });
+                Ok(())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
 
-                    self.classes.insert(&class_name, &(class_info.0, students));
+        //get an appeal, visible to the teacher of the class and everyone who can read the student's grades
+        #[ink(message)]
+        pub fn get_appeal(&self, appeal_id: AppealId) -> Result<Appeal> {
+            let appeal = if let Some(appeal) = self.appeals.get(appeal_id) { appeal } else { return Err(Error::AppealNotFound) };
+            let teacher = self.classes.get((&appeal.class_name, appeal.term_id)).map(|class_info| class_info.teacher);
+            if self.can_read_class(Self::env().caller(), appeal.student, &appeal.class_name, appeal.term_id) {
+                Ok(appeal)
+            } else if teacher == Some(Self::env().caller()) {
+                //removed teachers keep no access to their old classes
+                if self.is_member(Role::Teacher, Self::env().caller()) {
+                    Ok(appeal)
+                } else {
+                    Err(Error::TeacherNotRegistered)
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get every appeal of a student, oldest first
+        #[ink(message)]
+        pub fn get_student_appeals(&self, student_id: AccountId) -> Result<Vec<Appeal>> {
+            if self.can_read_grades(Self::env().caller(), student_id) {
+                Ok(self.student_appeals.get(student_id).unwrap_or_default().iter().filter_map(|id| self.appeals.get(id)).collect())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get every amendment of the scores of a student in a class, oldest first
+        #[ink(message)]
+        pub fn get_score_history(&self, class_name: String, term_id: TermId, student_id: AccountId) -> Result<Vec<Amendment>> {
+            if self.can_read_class(Self::env().caller(), student_id, &class_name, term_id) {
+                if !self.classes.contains((&class_name, term_id)) {
+                    return Err(Error::ClassNotFound)
+                }
+                Ok(self.score_history.get((student_id, &class_name, term_id)).unwrap_or_default())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get the final grade of a student in a class, if it was computed
+        #[ink(message)]
+        pub fn get_final_grade(&self, class_name: String, term_id: TermId, student_id: AccountId) -> Result<Option<FinalGrade>> {
+            if self.can_read_class(Self::env().caller(), student_id, &class_name, term_id) {
+                if !self.classes.contains((&class_name, term_id)) {
+                    return Err(Error::ClassNotFound)
+                }
+                Ok(self.final_grades.get((student_id, &class_name, term_id)))
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
 
-                    self.grades.take((&student_id, &class_name));
+        //change the grade points of each grade
+        #[ink(message)]
+        pub fn set_grade_points(&mut self, grade_points: Vec<(Grade, u32)>) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only class managers have access
+            if self.has_capability(Self::env().caller(), Capability::ManageClasses) {
+                //every grade can appear at most once and carries at most the maximum grade points
+                if !grade_points.iter().enumerate().all(|(index, (grade, _))| !grade_points[..index].iter().any(|(other, _)| other == grade)) {
+                    Err(Error::DuplicateGrade)
+                } else if grade_points.iter().any(|(_, points)| *points > MAX_GRADE_POINTS) {
+                    Err(Error::InvalidGradePoints)
+                } else {
+                    self.grade_points = grade_points.clone();
+                    Self::env().emit_event(GradePointsChanged { grade_points });
                     Ok(())
-                } else {{
-                    return Err(Error::InvalidInput) 
-                }}
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get the grade points of each grade
+        #[ink(message)]
+        pub fn get_grade_points(&self) -> Vec<(Grade, u32)> {
+            self.grade_points.clone()
+        }
+
+        //choose whether teachers can read every student's grades or only those of classes they teach or taught
+        #[ink(message)]
+        pub fn set_teacher_read_policy(&mut self, policy: TeacherReadPolicy) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only admins have access
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                self.teacher_read_policy = policy;
+                Self::env().emit_event(TeacherReadPolicyChanged { policy });
+                Ok(())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_teacher_read_policy(&self) -> TeacherReadPolicy {
+            self.teacher_read_policy
+        }
+
+        //compute the term and cumulative gpa of a student, weighted by credits, from their final grades
+        #[ink(message)]
+        pub fn get_gpa(&self, student_id: AccountId, term_id: TermId) -> Result<GpaReport> {
+            if self.can_read_grades(Self::env().caller(), student_id) {
+                let term = if let Some(term) = self.terms.get(term_id) { term } else { return Err(Error::TermNotFound) };
+
+                //points are summed in u64 so that many classes can not overflow them
+                let (mut term_points, mut term_credits, mut cumulative_points, mut cumulative_credits) = (0u64, 0u32, 0u64, 0u32);
+                for (class_name, class_term) in self.student_classes.get(student_id).unwrap_or_default().iter() {
+                    //cumulative gpa covers every term that started no later than the requested one
+                    if self.terms.get(class_term).is_some_and(|other| other.start > term.start) {
+                        continue
+                    }
+                    //only grades of completed classes count, computed grades of running classes are provisional
+                    let class_info = if let Some(class_info) = self.classes.get((class_name, *class_term)) { class_info } else { continue };
+                    if !self.is_completed(class_name, *class_term, &class_info) {
+                        continue
+                    }
+                    let final_grade = if let Some(final_grade) = self.final_grades.get((student_id, class_name, *class_term)) { final_grade } else { continue };
+                    let points = if let Some((_, points)) = self.grade_points.iter().find(|(grade, _)| *grade == final_grade.grade) { u64::from(*points) } else { continue };
+                    let credits = u32::from(class_info.credits);
+
+                    cumulative_points += points * u64::from(credits);
+                    cumulative_credits += credits;
+                    if *class_term == term_id {
+                        term_points += points * u64::from(credits);
+                        term_credits += credits;
+                    }
+                }
+
+                //an average never exceeds the highest grade points of a class, so it fits back into a u32
+                Ok(GpaReport {
+                    term_gpa: term_points.checked_div(u64::from(term_credits)).unwrap_or(0) as u32,
+                    term_credits,
+                    cumulative_gpa: cumulative_points.checked_div(u64::from(cumulative_credits)).unwrap_or(0) as u32,
+                    cumulative_credits,
+                })
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //the student adds any account to be able to access their grades
+        #[ink(message)]
+        pub fn add_accessstudents(&mut self, student_id: AccountId, new_access_id: AccountId) -> Result<()> {
+            self.grant_access(student_id, new_access_id, None, AccessScope::All)
+        }
+
+        //the student adds any account to be able to access their grades until a block timestamp
+        #[ink(message)]
+        pub fn add_accessstudents_until(&mut self, student_id: AccountId, new_access_id: AccountId, expires_at: Timestamp) -> Result<()> {
+            self.grant_access(student_id, new_access_id, Some(expires_at), AccessScope::All)
+        }
+
+        //the student adds any account to be able to access their grades of some classes or a term
+        #[ink(message)]
+        pub fn add_scoped_access(&mut self, student_id: AccountId, new_access_id: AccountId, scope: AccessScope, expires_at: Option<Timestamp>) -> Result<()> {
+            self.grant_access(student_id, new_access_id, expires_at, scope)
+        }
 
+        //request access to the grades of a student for yourself, or as an access manager for another account
+        #[ink(message)]
+        pub fn request_access(&mut self, student_id: AccountId, grantee: AccountId, scope: AccessScope, expires_at: Option<Timestamp>) -> Result<AccessRequestId> {
+            self.ensure_not_paused()?;
+            let caller = Self::env().caller();
+            if caller == grantee || self.has_capability(caller, Capability::ManageAccess) {
+                //only students can be asked and the grant must make sense
+                if !self.is_member(Role::Student, student_id) {
+                    Err(Error::StudentNotRegistered)
+                } else {
+                    self.check_grant(&scope, expires_at)?;
+                    //one pending request per grantee and a bounded number per student keep the pending requests of a student small
+                    //requests accounts make for themselves can not crowd out those of access managers
+                    if self.pending_access_requests.contains((student_id, grantee)) {
+                        return Err(Error::RequestAlreadyPending)
+                    }
+                    let for_self = caller == grantee;
+                    let pending = self.pending_request_counts.get((student_id, for_self)).unwrap_or_default();
+                    if pending >= MAX_PENDING_REQUESTS {
+                        return Err(Error::TooManyPendingRequests)
+                    }
+
+                    let id = self.access_request_count;
+                    self.access_requests.insert(id, &AccessRequest {
+                        id,
+                        student: student_id,
+                        grantee,
+                        requested_by: caller,
+                        scope,
+                        expires_at,
+                        status: AccessRequestStatus::Pending,
+                        decided_by: None,
+                    });
+                    self.access_request_count += 1;
+                    self.pending_access_requests.insert((student_id, grantee), &id);
+                    self.pending_request_counts.insert((student_id, for_self), &(pending + 1));
+                    let mut requests = self.student_access_requests.get(student_id).unwrap_or_default();
+                    requests.push(id);
+                    self.student_access_requests.insert(student_id, &requests);
+                    Self::env().emit_event(AccessRequested { request_id: id, student: student_id, grantee, requested_by: caller });
+                    Ok(id)
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //approve or reject an access request to your own grades
+        #[ink(message)]
+        pub fn decide_access_request(&mut self, request_id: AccessRequestId, approve: bool) -> Result<()> {
+            self.ensure_not_paused()?;
+            let request = if let Some(request) = self.access_requests.get(request_id) { request } else { return Err(Error::RequestNotFound) };
+            //only the student has access
+            if request.student == Self::env().caller() {
+                let status = if approve { AccessRequestStatus::Approved } else { AccessRequestStatus::Rejected };
+                self.decide(request, status)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //grant an access request without the student's approval, the request is marked as overridden
+        #[ink(message)]
+        pub fn override_access_request(&mut self, request_id: AccessRequestId) -> Result<()> {
+            self.ensure_not_paused()?;
+            //only admins have access
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                let request = if let Some(request) = self.access_requests.get(request_id) { request } else { return Err(Error::RequestNotFound) };
+                self.decide(request, AccessRequestStatus::Overridden)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get an access request, visible to the requester, the grantee and everyone who can read the student's grades
+        #[ink(message)]
+        pub fn get_access_request(&self, request_id: AccessRequestId) -> Result<AccessRequest> {
+            let request = if let Some(request) = self.access_requests.get(request_id) { request } else { return Err(Error::RequestNotFound) };
+            let caller = Self::env().caller();
+            if caller == request.requested_by || caller == request.grantee || self.can_read_grades(caller, request.student) {
+                Ok(request)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get every access request concerning a student, oldest first
+        #[ink(message)]
+        pub fn get_access_requests(&self, student_id: AccountId) -> Result<Vec<AccessRequest>> {
+            if self.can_read_grades(Self::env().caller(), student_id) {
+                Ok(self.student_access_requests.get(student_id).unwrap_or_default().iter().filter_map(|id| self.access_requests.get(id)).collect())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //list the active or the expired access grants of a student
+        #[ink(message)]
+        pub fn get_access_grants(&self, student_id: AccountId, active: bool) -> Result<Vec<AccessGrant>> {
+            if self.can_read_grades(Self::env().caller(), student_id) {
+                let now = Self::env().block_timestamp();
+                Ok(self.accessstudents.get(student_id).unwrap_or_default().into_iter().filter(|grant| grant.is_active(now) == active).collect())
             } else {
-                return Err(Error::AccessNotAllowed) 
+                Err(Error::AccessNotAllowed)
             }
+        }
+
+        //access the grades of a student for a specific class
+        #[ink(message)]
+        pub fn access_grades(&self,class_name: String, term_id: TermId, student_id: AccountId) -> Result<Vec<Score>> {
+            if self.can_read_class(Self::env().caller(), student_id, &class_name, term_id) {
+                if !self.classes.contains((&class_name, term_id)) {
+                    return Err(Error::ClassNotFound)
+                }
+                //get and return grades
+                let current_grades = self.grades.get((student_id, &class_name, term_id)).unwrap_or_default();
+                Ok(current_grades)
+            } else {
+                Err(Error::AccessNotAllowed) 
+            }
+            
+        }
+
+        //access the grades of a student for every class they take in a term
+        #[ink(message)]
+        pub fn access_term_grades(&self, term_id: TermId, student_id: AccountId) -> Result<Vec<(String, Vec<Score>)>> {
+            if let Some(scope) = self.access_scope(Self::env().caller(), student_id) {
+                if !self.terms.contains(term_id) {
+                    return Err(Error::TermNotFound)
+                }
+                let term_grades = self.student_classes.get(student_id).unwrap_or_default().into_iter()
+                    .filter(|(class_name, class_term)| *class_term == term_id && scope.covers(class_name, term_id))
+                    .map(|(class_name, _)| (class_name.clone(), self.grades.get((student_id, &class_name, term_id)).unwrap_or_default()))
+                    .collect();
+                Ok(term_grades)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //access every class of a student with teacher, credits, scores and final grade, ordered by term
+        #[ink(message)]
+        pub fn get_transcript(&self, student_id: AccountId) -> Result<Vec<TranscriptEntry>> {
+            if let Some(scope) = self.access_scope(Self::env().caller(), student_id) {
+                let mut transcript: Vec<TranscriptEntry> = self.student_classes.get(student_id).unwrap_or_default().into_iter()
+                    .filter(|(class_name, term_id)| scope.covers(class_name, *term_id))
+                    .filter_map(|(class_name, term_id)| {
+                        let class_info = self.classes.get((&class_name, term_id))?;
+                        //provisional grades of classes that are not completed are left out
+                        let completed = self.is_completed(&class_name, term_id, &class_info);
+                        let final_grade = if completed { self.final_grades.get((student_id, &class_name, term_id)) } else { None };
+                        Some(TranscriptEntry {
+                            scores: self.grades.get((student_id, &class_name, term_id)).unwrap_or_default(),
+                            class_name,
+                            term_id,
+                            teacher: class_info.teacher,
+                            credits: class_info.credits,
+                            completed,
+                            final_grade,
+                        })
+                    })
+                    .collect();
+                transcript.sort_by_key(|entry| entry.term_id);
+                Ok(transcript)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //compute the weighted percentage of a student in a class, in hundredths of a percent
+        #[ink(message)]
+        pub fn final_percentage(&self, class_name: String, term_id: TermId, student_id: AccountId) -> Result<u32> {
+            if self.can_read_class(Self::env().caller(), student_id, &class_name, term_id) {
+                if self.classes.contains((&class_name, term_id)) {
+                    Ok(self.weighted_percentage(&class_name, term_id, student_id))
+                } else {
+                    Err(Error::ClassNotFound)
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //remove a person from the access list of a student
+        #[ink(message)]
+        pub fn remove_accessstudents(&mut self, student_id: AccountId, remove_access_id: AccountId) -> Result<()> {
+            self.ensure_not_paused()?;
+            
+            //only access managers or the specific student specified in the input can change this
+            if self.has_capability(Self::env().caller(), Capability::ManageAccess) || Self::env().caller() == student_id {
+                if !self.is_member(Role::Student, student_id) {
+                    return Err(Error::StudentNotRegistered)
+                }
+                //the student always keeps access to their own grades
+                if remove_access_id == student_id {
+                    return Err(Error::CannotRevokeOwnAccess)
+                }
+
+                let mut current_access = self.accessstudents.get(student_id).unwrap_or_default();
+                if let Some(index) = current_access.iter().position(|grant| grant.grantee == remove_access_id) {
+                    current_access.remove(index);
+                    self.accessstudents.insert(student_id, &current_access);
+                    Self::env().emit_event(AccessRevoked { student: student_id, grantee: remove_access_id });
+                    Ok(())
+                } else {
+                    Err(Error::GrantNotFound)
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+            
+        }
+
+        //remove everyone but the student from the access list of a student
+        #[ink(message)]
+        pub fn revoke_all_access(&mut self, student_id: AccountId) -> Result<()> {
+            self.ensure_not_paused()?;
+            //only access managers or the specific student specified in the input can change this
+            if self.has_capability(Self::env().caller(), Capability::ManageAccess) || Self::env().caller() == student_id {
+                if !self.is_member(Role::Student, student_id) {
+                    return Err(Error::StudentNotRegistered)
+                }
+                let current_access = self.accessstudents.get(student_id).unwrap_or_default();
+                for grant in current_access.iter().filter(|grant| grant.grantee != student_id) {
+                    Self::env().emit_event(AccessRevoked { student: student_id, grantee: grant.grantee });
+                }
+                let remaining: Vec<AccessGrant> = current_access.into_iter().filter(|grant| grant.grantee == student_id).collect();
+                self.accessstudents.insert(student_id, &remaining);
+                Ok(())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        #[ink(message)]
+        pub fn remove_admins(&mut self, admin_id: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+            let caller = Self::env().caller();
+
+            //above a threshold of one admins can only be removed through a proposal
+            if self.can_administer(caller, Role::Admin) {
+                if self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
+                }
+                self.check_admin_removal(admin_id)?;
+                //admins can resign on their own, removing another admin needs a second admin to confirm
+                if admin_id == caller {
+                    self.revoke(Role::Admin, admin_id)
+                } else if admin_id != self.owner {
+                    self.admin_removals.insert(admin_id, &caller);
+                    Self::env().emit_event(AdminRemovalProposed { admin: admin_id, proposed_by: caller });
+                    Ok(())
+                } else {
+                    Err(Error::OwnerNotRemovable)
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //confirm the removal of an admin asked for by another admin
+        #[ink(message)]
+        pub fn confirm_admin_removal(&mut self, admin_id: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+            let caller = Self::env().caller();
+            let proposed_by = if let Some(proposed_by) = self.admin_removals.get(admin_id) { proposed_by } else { return Err(Error::RemovalNotProposed) };
+
+            //only an admin other than the one who asked for the removal has access
+            if self.is_member(Role::Admin, caller) && caller != proposed_by {
+                if self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
+                }
+                self.check_admin_removal(admin_id)?;
+                self.revoke(Role::Admin, admin_id)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get the admin who asked for the removal of an admin, if any
+        #[ink(message)]
+        pub fn get_admin_removal(&self, admin_id: AccountId) -> Option<AccountId> {
+            self.admin_removals.get(admin_id)
+        }
+
+        //offer ownership to another account, which has to accept it
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only the owner has access, above a threshold of one ownership moves through a proposal
+            if Self::env().caller() == self.owner {
+                if self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
+                }
+                self.offer_ownership(new_owner);
+                Ok(())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //accept ownership offered by the owner, the new owner becomes an admin unless that needs a proposal
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<()>{
+            self.ensure_not_paused()?;
+            let caller = Self::env().caller();
+            //only the account ownership was offered to has access
+            if self.pending_owner == Some(caller) {
+                let previous_owner = self.owner;
+                self.owner = caller;
+                self.pending_owner = None;
+                if self.admin_threshold == 1 && !self.is_member(Role::Admin, caller) {
+                    self.grant(Role::Admin, caller)?;
+                }
+                Self::env().emit_event(OwnershipTransferred { previous_owner, new_owner: caller });
+                Ok(())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //restore an admin while fewer admins remain than proposals need, this also works while paused so that someone can unpause
+        #[ink(message)]
+        pub fn recover_admin(&mut self, admin_id: AccountId) -> Result<()>{
+            //only the owner has access
+            if Self::env().caller() == self.owner {
+                if self.member_count(Role::Admin) >= MIN_ADMINS.max(self.admin_threshold) {
+                    return Err(Error::EnoughAdmins)
+                }
+                self.grant(Role::Admin, admin_id)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
+        }
+
+        //stop every change to the registry, reads stay available
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<()>{
+            //only admins have access
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                self.ensure_not_paused()?;
+                self.paused.set(&true);
+                Self::env().emit_event(Paused { account: Self::env().caller() });
+                Ok(())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //accept changes again, above a threshold of one this needs a proposal
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<()>{
+            //only admins have access
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                if self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
+                }
+                self.resume()
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused.get().unwrap_or(false)
+        }
+
+        //replace the contract code, migrate must be called afterwards if the new code raised the storage version
+        #[ink(message)]
+        pub fn upgrade(&mut self, code_hash: Hash) -> Result<()>{
+            //only admins have access, above a threshold of one upgrades need a proposal
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                if self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
+                }
+                self.set_code(code_hash)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //bring the stored data to the layout of the current code, one version at a time
+        #[ink(message)]
+        pub fn migrate(&mut self) -> Result<()>{
+            //only admins have access
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                let from_version = self.storage_version;
+                //there must be something to migrate
+                if from_version >= STORAGE_VERSION {
+                    return Err(Error::NothingToMigrate)
+                }
+
+                while self.storage_version < STORAGE_VERSION {
+                    match self.storage_version {
+                        //version 1 had no pause flag, contracts upgraded from it start unpaused
+                        1 => self.paused.set(&false),
+                        _ => return Err(Error::UnknownStorageVersion),
+                    }
+                    self.storage_version += 1;
+                }
+                Self::env().emit_event(Migrated { from_version, to_version: self.storage_version });
+                Ok(())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_storage_version(&self) -> u32 {
+            self.storage_version
+        }
+
+        //archive a class, its grades stay readable under the access rules
+        #[ink(message)]
+        pub fn remove_classes(&mut self,class_name: String, term_id: TermId) -> Result<()>{
+            self.ensure_not_paused()?;
+            //above a threshold of one classes can only be removed through a proposal
+            if self.has_capability(Self::env().caller(), Capability::ManageClasses) {
+                if self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
+                }
+                self.archive_class(class_name, term_id)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //propose a sensitive action, which is carried out once enough admins approved it
+        #[ink(message)]
+        pub fn propose(&mut self, action: AdminAction) -> Result<ProposalId>{
+            //only admins have access
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                let id = self.proposal_count;
+                let now = Self::env().block_timestamp();
+                let mut proposal = Proposal {
+                    id,
+                    action: action.clone(),
+                    proposer: Self::env().caller(),
+                    approvals: [Self::env().caller()].to_vec(),
+                    created_at: now,
+                    expires_at: now + PROPOSAL_WINDOW,
+                    status: ProposalStatus::Pending,
+                };
+                self.proposal_count += 1;
+                Self::env().emit_event(ProposalCreated { proposal_id: id, proposer: Self::env().caller(), action, expires_at: proposal.expires_at });
+                self.execute_if_approved(&mut proposal)?;
+                self.proposals.insert(id, &proposal);
+                Ok(id)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //approve a pending proposal within its window
+        #[ink(message)]
+        pub fn approve_proposal(&mut self, proposal_id: ProposalId) -> Result<()>{
+            //only admins have access
+            if self.is_member(Role::Admin, Self::env().caller()) {
+                let mut proposal = if let Some(proposal) = self.proposals.get(proposal_id) { proposal } else { return Err(Error::ProposalNotFound) };
+                //each admin approves once and only while the proposal is open
+                if proposal.status != ProposalStatus::Pending || Self::env().block_timestamp() >= proposal.expires_at {
+                    return Err(Error::ProposalClosed)
+                }
+                if proposal.approvals.contains(&Self::env().caller()) {
+                    return Err(Error::AlreadyApproved)
+                }
+                proposal.approvals.push(Self::env().caller());
+                Self::env().emit_event(ProposalApproved { proposal_id, approver: Self::env().caller() });
+                self.execute_if_approved(&mut proposal)?;
+                self.proposals.insert(proposal_id, &proposal);
+                Ok(())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get a proposal, pending proposals past their window are reported as expired
+        #[ink(message)]
+        pub fn get_proposal(&self, proposal_id: ProposalId) -> Result<Proposal> {
+            let mut proposal = if let Some(proposal) = self.proposals.get(proposal_id) { proposal } else { return Err(Error::ProposalNotFound) };
+            if proposal.status == ProposalStatus::Pending && Self::env().block_timestamp() >= proposal.expires_at {
+                proposal.status = ProposalStatus::Expired;
+            }
+            Ok(proposal)
+        }
+
+        #[ink(message)]
+        pub fn get_admin_threshold(&self) -> u32 {
+            self.admin_threshold
+        }
+
+        //unenroll a student and give the free seat to the first student on the waitlist, waitlisted students just leave the waitlist
+        #[ink(message)]
+        pub fn unenroll_student(&mut self,class_name: String, term_id: TermId, student_id: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+            if self.has_capability(Self::env().caller(), Capability::ManageEnrollment) {
+                if self.leave_waitlist(&class_name, term_id, student_id) {
+                    Self::env().emit_event(StudentLeftWaitlist { class_name, term_id, student: student_id });
+                    return Ok(())
+                }
+                //above a threshold of one enrolled students can only be unenrolled through a proposal
+                if self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
+                }
+                self.unenroll_and_fill(class_name, term_id, student_id)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //enroll a student, students are put on the waitlist once the class is full
+        #[ink(message)]
+        pub fn enroll_student(&mut self,class_name: String, term_id: TermId, student_id: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+            if self.has_capability(Self::env().caller(), Capability::ManageEnrollment) {
+
+                let mut class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+                if !self.is_member(Role::Student, student_id) {
+                    Err(Error::StudentNotRegistered)
+                } else if !self.is_active_student(student_id) {
+                    Err(Error::StudentNotActive)
+                } else if class_info.students.contains(&student_id) {
+                    Err(Error::StudentAlreadyEnrolled)
+                } else if self.waitlists.get((&class_name, term_id)).unwrap_or_default().contains(&student_id) {
+                    Err(Error::StudentAlreadyWaitlisted)
+                } else if class_info.status != ClassStatus::Open {
+                    Err(Error::ClassNotOpen)
+                } else if !self.is_term_open(term_id) {
+                    Err(Error::TermClosed)
+                } else if !self.has_free_seat(&class_name, term_id, &class_info) {
+                    let position = self.join_waitlist(&class_name, term_id, student_id);
+                    Self::env().emit_event(StudentWaitlisted { class_name, term_id, student: student_id, position });
+                    Ok(())
+                } else {
+                    self.enroll(&class_name, term_id, &mut class_info, student_id);
+                    Ok(())
+                }
+
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+
+        }
+
+
+        //limit the seats of a class, without a capacity the class has unlimited seats
+        #[ink(message)]
+        pub fn set_class_capacity(&mut self, class_name: String, term_id: TermId, capacity: Option<u32>) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only class managers have access
+            if self.has_capability(Self::env().caller(), Capability::ManageClasses) {
+                let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+                if class_info.status == ClassStatus::Archived {
+                    return Err(Error::ClassArchived)
+                }
+                if let Some(capacity) = capacity {
+                    if capacity == 0 || capacity < class_info.students.len() as u32 {
+                        return Err(Error::InvalidCapacity)
+                    }
+                    self.class_capacities.insert((&class_name, term_id), &capacity);
+                } else {
+                    self.class_capacities.remove((&class_name, term_id));
+                }
+                Self::env().emit_event(ClassCapacityChanged { class_name: class_name.clone(), term_id, capacity });
+
+                //new seats go to the waitlist
+                self.fill_seats(&class_name, term_id);
+                Ok(())
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get the enrolled students, capacity and waitlist length of a class
+        #[ink(message)]
+        pub fn get_seats(&self, class_name: String, term_id: TermId) -> Result<SeatCount> {
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+            Ok(SeatCount {
+                enrolled: class_info.students.len() as u32,
+                capacity: self.class_capacities.get((&class_name, term_id)),
+                waitlisted: self.waitlists.get((&class_name, term_id)).unwrap_or_default().len() as u32,
+            })
+        }
+
+        //list the waitlist of a class, the next student to get a seat first
+        #[ink(message)]
+        pub fn get_waitlist(&self, class_name: String, term_id: TermId) -> Vec<AccountId> {
+            self.waitlists.get((&class_name, term_id)).unwrap_or_default()
+        }
+
+        //get the position of a student on the waitlist of a class, counted from 1
+        #[ink(message)]
+        pub fn get_waitlist_position(&self, class_name: String, term_id: TermId, student_id: AccountId) -> Option<u32> {
+            self.waitlists.get((&class_name, term_id)).unwrap_or_default().iter().position(|student| *student == student_id).map(|index| index as u32 + 1)
+        }
+
+        //let a teaching assistant record scores and read the grades of a class
+        #[ink(message)]
+        pub fn add_class_assistant(&mut self, class_name: String, term_id: TermId, assistant_id: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            //accessing class info
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            //only the teacher of the class and class managers have access
+            if self.can_manage_class(Self::env().caller(), &class_info) {
+                let mut assistants = self.class_assistants.get((&class_name, term_id)).unwrap_or_default();
+                if class_info.status == ClassStatus::Archived {
+                    Err(Error::ClassArchived)
+                } else if !self.is_member(Role::TeachingAssistant, assistant_id) {
+                    Err(Error::RoleNotHeld)
+                } else if assistants.contains(&assistant_id) {
+                    Err(Error::AssistantAlreadyAdded)
+                } else {
+                    assistants.push(assistant_id);
+                    self.class_assistants.insert((&class_name, term_id), &assistants);
+                    Self::env().emit_event(ClassAssistantAdded { class_name, term_id, assistant: assistant_id });
+                    Ok(())
+                }
+            } else {
+                Err(Error::NotClassTeacher)
+            }
+        }
+
+        //take a teaching assistant off a class
+        #[ink(message)]
+        pub fn remove_class_assistant(&mut self, class_name: String, term_id: TermId, assistant_id: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            //accessing class info
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            //only the teacher of the class and class managers have access
+            if self.can_manage_class(Self::env().caller(), &class_info) {
+                let mut assistants = self.class_assistants.get((&class_name, term_id)).unwrap_or_default();
+                if let Some(index) = assistants.iter().position(|assistant| *assistant == assistant_id) {
+                    assistants.remove(index);
+                    self.class_assistants.insert((&class_name, term_id), &assistants);
+                    Self::env().emit_event(ClassAssistantRemoved { class_name, term_id, assistant: assistant_id });
+                    Ok(())
+                } else {
+                    Err(Error::AssistantNotFound)
+                }
+            } else {
+                Err(Error::NotClassTeacher)
+            }
+        }
+
+        //list the teaching assistants of a class
+        #[ink(message)]
+        pub fn get_class_assistants(&self, class_name: String, term_id: TermId) -> Vec<AccountId> {
+            self.class_assistants.get((&class_name, term_id)).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn change_teacher(&mut self,class_name: String, term_id: TermId, teacher_id: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+
+            if self.has_capability(Self::env().caller(), Capability::ManageClasses) {
+
+                
+
+                let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+                if class_info.status == ClassStatus::Archived {
+                    return Err(Error::ClassArchived)
+                }
+                if self.is_member(Role::Teacher, teacher_id)  {
+                    self.set_teacher(class_name, term_id, class_info, teacher_id);
+                    Ok(())
+                } else {
+                    Err(Error::TeacherNotRegistered)
+                }
+
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+
+        }
+
+        //hand over every class of a teacher that is not finalized yet, returns the number of reassigned classes
+        #[ink(message)]
+        pub fn reassign_classes(&mut self, from: AccountId, to: AccountId) -> Result<u32>{
+            self.ensure_not_paused()?;
+
+            if self.has_capability(Self::env().caller(), Capability::ManageClasses) {
+                if !self.is_member(Role::Teacher, to) {
+                    return Err(Error::TeacherNotRegistered)
+                }
+                let classes = self.active_classes_of(from);
+                for (class_name, term_id) in classes.iter() {
+                    if let Some(class_info) = self.classes.get((class_name, *term_id)) {
+                        self.set_teacher(class_name.clone(), *term_id, class_info, to);
+                    }
+                }
+                Ok(classes.len() as u32)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //list the classes of a teacher that are not finalized, these block the removal of the teacher
+        #[ink(message)]
+        pub fn get_active_classes(&self, teacher_id: AccountId) -> Vec<(String, TermId)> {
+            self.active_classes_of(teacher_id)
+        }
+
+        //teachers can only be removed once their active classes are reassigned or finalized
+        #[ink(message)]
+        pub fn remove_teacher(&mut self, teacher_id: AccountId) -> Result<()>{
+            self.revoke_role(Role::Teacher, teacher_id)
+        }
+
+
+        //archive a student as withdrawn, their grades stay readable under the access rules and only a purge erases them
+        //archived students keep their seats so that they can be made active again, a purge hands the seats to the waitlist
+        #[ink(message)]
+        pub fn remove_student(&mut self, student_id: AccountId) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only admins and holders of the student role's admin role have access
+            //students can only be archived through a proposal above a threshold of one
+            if self.can_administer(Self::env().caller(), Role::Student) {
+                if self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
+                }
+                if self.is_member(Role::Student, student_id) {
+                    self.archive_student(student_id)
+                } else {
+                    Err(Error::StudentNotRegistered)
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //change the status of a student, archived students can be made active again
+        #[ink(message)]
+        pub fn set_student_status(&mut self, student_id: AccountId, status: StudentStatus) -> Result<()>{
+            self.ensure_not_paused()?;
+            //only enrollment managers have access, above a threshold of one students are only archived through a proposal
+            if self.has_capability(Self::env().caller(), Capability::ManageEnrollment) {
+                if status != StudentStatus::Active && self.admin_threshold > 1 {
+                    return Err(Error::ProposalRequired)
+                }
+                if self.is_member(Role::Student, student_id) {
+                    self.set_status(student_id, status);
+                    Ok(())
+                } else {
+                    Err(Error::StudentNotRegistered)
+                }
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //get the status of a registered student
+        #[ink(message)]
+        pub fn get_student_status(&self, student_id: AccountId) -> Option<StudentStatus> {
+            if self.is_member(Role::Student, student_id) {
+                Some(self.student_status.get(student_id).unwrap_or(StudentStatus::Active))
+            } else {
+                None
+            }
+        }
+
+        //list all admins
+        #[ink(message)]
+        pub fn get_admins(&self) -> Vec<AccountId> {
+            self.members(Role::Admin)
+        }
+
+        //list all teachers
+        #[ink(message)]
+        pub fn get_teachers(&self) -> Vec<AccountId> {
+            self.members(Role::Teacher)
+        }
+
+        //list all students
+        #[ink(message)]
+        pub fn get_students(&self) -> Vec<AccountId> {
+            self.members(Role::Student)
+        }
+
+        //admins, registrars, advisors and auditors can read every grade, teachers and teaching assistants depending on the read policy
+        //and people on the allow list within the scope of their grant
+        fn access_scope(&self, caller: AccountId, student_id: AccountId) -> Option<AccessScope> {
+            let teaches = self.is_member(Role::Teacher, caller) || self.is_member(Role::TeachingAssistant, caller);
+            if self.has_capability(caller, Capability::ReadGrades) || (teaches && self.teacher_read_policy == TeacherReadPolicy::AllStudents) {
+                return Some(AccessScope::All)
+            }
+            let now = Self::env().block_timestamp();
+            let grant = self.accessstudents.get(student_id).unwrap_or_default().into_iter()
+                .find(|grant| grant.grantee == caller && grant.is_active(now))
+                .map(|grant| grant.scope);
+            if grant == Some(AccessScope::All) || !teaches {
+                return grant
+            }
+
+            //teachers add the classes of the student they teach or taught, teaching assistants the classes they assist
+            let classes: Vec<(String, TermId)> = self.student_classes.get(student_id).unwrap_or_default().into_iter()
+                .filter(|(class_name, term_id)| {
+                    grant.as_ref().is_some_and(|scope| scope.covers(class_name, *term_id))
+                        || (self.is_member(Role::Teacher, caller) && self.class_teachers.get((class_name, *term_id)).unwrap_or_default().contains(&caller))
+                        || self.assists_class(caller, class_name, *term_id)
+                })
+                .collect();
+            if classes.is_empty() { grant } else { Some(AccessScope::Classes(classes)) }
+        }
+
+        //teaching assistants assist the classes they were added to while they hold the role
+        fn assists_class(&self, account: AccountId, class_name: &String, term_id: TermId) -> bool {
+            self.is_member(Role::TeachingAssistant, account) && self.class_assistants.get((class_name, term_id)).unwrap_or_default().contains(&account)
+        }
+
+        //can read every grade of a student
+        fn can_read_grades(&self, caller: AccountId, student_id: AccountId) -> bool {
+            self.access_scope(caller, student_id) == Some(AccessScope::All)
+        }
+
+        //can read the grades of a student in one class
+        fn can_read_class(&self, caller: AccountId, student_id: AccountId, class_name: &str, term_id: TermId) -> bool {
+            self.access_scope(caller, student_id).is_some_and(|scope| scope.covers(class_name, term_id))
+        }
+
+        //add an access grant directly, which only the student can do
+        fn grant_access(&mut self, student_id: AccountId, new_access_id: AccountId, expires_at: Option<Timestamp>, scope: AccessScope) -> Result<()> {
+            self.ensure_not_paused()?;
+            //only the specific student specified in the input can change this, everyone else has to request access
+            if Self::env().caller() == student_id {
+                if !self.is_member(Role::Student, student_id) {
+                    return Err(Error::StudentNotRegistered)
+                }
+                self.insert_grant(student_id, new_access_id, expires_at, scope)
+            } else {
+                Err(Error::AccessNotAllowed)
+            }
+        }
+
+        //add an access grant, expired grants of the same account are replaced
+        fn insert_grant(&mut self, student_id: AccountId, new_access_id: AccountId, expires_at: Option<Timestamp>, scope: AccessScope) -> Result<()> {
+            let now = Self::env().block_timestamp();
+            self.check_grant(&scope, expires_at)?;
+            let mut current_access = self.accessstudents.get(student_id).unwrap_or_default();
+            //must be a new acount id or an expired grant
+            if !current_access.iter().any(|grant| grant.grantee == new_access_id && grant.is_active(now)) {
+                current_access.retain(|grant| grant.grantee != new_access_id);
+                current_access.push(AccessGrant { grantee: new_access_id, expires_at, scope: scope.clone() });
+                self.accessstudents.insert(student_id, &current_access);
+                Self::env().emit_event(AccessGranted { student: student_id, grantee: new_access_id, expires_at, scope });
+                Ok(())
+            } else {
+                Err(Error::DuplicateGrant)
+            }
+        }
+
+        //the grant must not be expired already and must cover at least one class
+        fn check_grant(&self, scope: &AccessScope, expires_at: Option<Timestamp>) -> Result<()> {
+            if expires_at.is_some_and(|expires_at| expires_at <= Self::env().block_timestamp()) {
+                Err(Error::InvalidExpiry)
+            } else if *scope == AccessScope::Classes(Vec::new()) {
+                Err(Error::EmptyScope)
+            } else {
+                Ok(())
+            }
+        }
+
+        //close a pending access request, putting the grant in place unless it is rejected
+        fn decide(&mut self, mut request: AccessRequest, status: AccessRequestStatus) -> Result<()> {
+            if request.status != AccessRequestStatus::Pending {
+                return Err(Error::RequestAlreadyDecided)
+            }
+            if status != AccessRequestStatus::Rejected {
+                self.insert_grant(request.student, request.grantee, request.expires_at, request.scope.clone())?;
+            }
+            request.status = status;
+            request.decided_by = Some(Self::env().caller());
+            self.access_requests.insert(request.id, &request);
+            self.pending_access_requests.remove((request.student, request.grantee));
+            let for_self = request.requested_by == request.grantee;
+            let pending = self.pending_request_counts.get((request.student, for_self)).unwrap_or_default();
+            self.pending_request_counts.insert((request.student, for_self), &pending.saturating_sub(1));
+            Self::env().emit_event(AccessRequestDecided { request_id: request.id, decided_by: Self::env().caller(), status });
+            Ok(())
+        }
+
+        //weigh the average score of every component, components without scores count as zero
+        fn weighted_percentage(&self, class_name: &String, term_id: TermId, student_id: AccountId) -> u32 {
+            let components = self.components.get((class_name, term_id)).unwrap_or_default();
+            let scores = self.grades.get((student_id, class_name, term_id)).unwrap_or_default();
+            let total_weight: u128 = components.iter().map(|component| u128::from(component.weight)).sum();
+            if total_weight == 0 {
+                return 0
+            }
+
+            let weighted: u128 = components.iter().map(|component| {
+                let component_scores = scores.iter().filter(|score| score.component == component.name);
+                let (count, points) = component_scores.fold((0u128, 0u128), |(count, points), score| (count + 1, points + u128::from(score.points)));
+                if count == 0 {
+                    0
+                } else {
+                    u128::from(component.weight) * points * u128::from(FULL_PERCENTAGE) / (count * u128::from(component.max_points))
+                }
+            }).sum();
+            (weighted / total_weight) as u32
+        }
+
+        //replace a recorded score and keep the previous value in the history
+        fn amend(&mut self, class_name: String, term_id: TermId, student_id: AccountId, index: u32, points: u32, reason: String) -> Result<()> {
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+            let mut current_grades = self.grades.get((student_id, &class_name, term_id)).unwrap_or_default();
+            let score = if let Some(score) = current_grades.get_mut(index as usize) { score } else { return Err(Error::ScoreNotFound) };
+            let components = self.components.get((&class_name, term_id)).unwrap_or_default();
+            if class_info.status == ClassStatus::Archived {
+                return Err(Error::ClassArchived)
+            }
+            //every amendment needs a reason and the new points must not exceed the maximum of the component
+            if reason.is_empty() {
+                return Err(Error::MissingReason)
+            }
+            Self::check_points(&components, &score.component, points)?;
+
+            let old_points = score.points;
+            score.points = points;
+            self.grades.insert((student_id, &class_name, term_id), &current_grades);
+
+            let mut history = self.score_history.get((student_id, &class_name, term_id)).unwrap_or_default();
+            history.push(Amendment { index, old_points, new_points: points, amended_by: Self::env().caller(), timestamp: Self::env().block_timestamp(), reason: reason.clone() });
+            self.score_history.insert((student_id, &class_name, term_id), &history);
+            Self::env().emit_event(ScoreAmended { class_name: class_name.clone(), term_id, student: student_id, index, old_points, new_points: points, amended_by: Self::env().caller(), reason });
+
+            //reissue the final grade of finalized classes
+            if class_info.status == ClassStatus::Finalized {
+                self.record_final_grade(&class_name, term_id, student_id);
+            }
+            Ok(())
+        }
+
+        //the teacher of a class and class managers can manage a class, removed teachers lose access
+        fn can_manage_class(&self, account: AccountId, class_info: &ClassInfo) -> bool {
+            (class_info.teacher == account && self.is_member(Role::Teacher, account)) || self.has_capability(account, Capability::ManageClasses)
+        }
+
+        //classes taught by an account that are not finalized or archived
+        fn active_classes_of(&self, teacher_id: AccountId) -> Vec<(String, TermId)> {
+            self.class_list.iter()
+                .filter(|(class_name, term_id)| self.classes.get((class_name, *term_id))
+                    .is_some_and(|class_info| class_info.teacher == teacher_id && matches!(class_info.status, ClassStatus::Open | ClassStatus::Grading)))
+                .cloned()
+                .collect()
+        }
+
+        //make an account the teacher of a class and remember it among the teachers of the class
+        fn set_teacher(&mut self, class_name: String, term_id: TermId, mut class_info: ClassInfo, teacher_id: AccountId) {
+            let old_teacher = class_info.teacher;
+            class_info.teacher = teacher_id;
+
+            self.classes.insert((&class_name, term_id), &class_info);
+            let mut teachers = self.class_teachers.get((&class_name, term_id)).unwrap_or_default();
+            teachers.retain(|teacher| *teacher != teacher_id);
+            teachers.push(teacher_id);
+            self.class_teachers.insert((&class_name, term_id), &teachers);
+            Self::env().emit_event(TeacherChanged { class_name, term_id, old_teacher, new_teacher: teacher_id });
+        }
+
+        //compute and store the final grade of a student in a class
+        fn record_final_grade(&mut self, class_name: &String, term_id: TermId, student_id: AccountId) {
+            let scale = self.get_grading_scale(class_name.clone(), term_id);
+            let percentage = self.weighted_percentage(class_name, term_id, student_id);
+            let grade = Self::grade_for(&scale, percentage);
+            self.final_grades.insert((student_id, class_name, term_id), &FinalGrade { percentage, grade });
+            Self::env().emit_event(FinalGradeRecorded { class_name: class_name.clone(), term_id, student: student_id, percentage, grade });
+        }
+
+        //thresholds must be at most 100%, strictly decreasing and end at 0
+        fn is_valid_scale(scale: &GradingScale) -> bool {
+            scale.first().is_some_and(|(threshold, _)| *threshold <= FULL_PERCENTAGE)
+                && scale.windows(2).all(|pair| pair[0].0 > pair[1].0)
+                && scale.last().is_some_and(|(threshold, _)| *threshold == 0)
+        }
+
+        //the component must exist and the points must not exceed its maximum
+        fn check_points(components: &[Component], component: &str, points: u32) -> Result<()> {
+            match components.iter().find(|c| c.name == component) {
+                None => Err(Error::ComponentNotFound),
+                Some(c) if points > c.max_points => Err(Error::PointsExceedMax),
+                Some(_) => Ok(()),
+            }
+        }
+
+        //the grade of the highest threshold reached by a percentage
+        fn grade_for(scale: &GradingScale, percentage: u32) -> Grade {
+            scale.iter().find(|(threshold, _)| percentage >= *threshold).map_or(Grade::F, |(_, grade)| *grade)
+        }
+
+        //record that a student takes a class, once
+        fn add_student_class(&mut self, student_id: AccountId, class_name: &str, term_id: TermId) {
+            let mut student_classes = self.student_classes.get(student_id).unwrap_or_default();
+            if !student_classes.iter().any(|(name, term)| name.as_str() == class_name && *term == term_id) {
+                student_classes.push((class_name.into(), term_id));
+                self.student_classes.insert(student_id, &student_classes);
+            }
+        }
+
+        //forget that a student takes a class
+        fn remove_student_class(&mut self, student_id: AccountId, class_name: &str, term_id: TermId) {
+            let mut student_classes = self.student_classes.get(student_id).unwrap_or_default();
+            student_classes.retain(|(name, term)| name.as_str() != class_name || *term != term_id);
+            self.student_classes.insert(student_id, &student_classes);
+        }
+
+        //remember the score history a student left behind in a class
+        fn add_dropout(&mut self, student_id: AccountId, class_name: &String, term_id: TermId) {
+            let mut class_dropouts = self.class_dropouts.get((class_name, term_id)).unwrap_or_default();
+            class_dropouts.push(student_id);
+            self.class_dropouts.insert((class_name, term_id), &class_dropouts);
+            let mut student_dropouts = self.student_dropouts.get(student_id).unwrap_or_default();
+            student_dropouts.push((class_name.clone(), term_id));
+            self.student_dropouts.insert(student_id, &student_dropouts);
+        }
+
+        //forget that a student left a score history behind in a class
+        fn remove_dropout(&mut self, student_id: AccountId, class_name: &String, term_id: TermId) {
+            let mut class_dropouts = self.class_dropouts.get((class_name, term_id)).unwrap_or_default();
+            class_dropouts.retain(|student| *student != student_id);
+            self.class_dropouts.insert((class_name, term_id), &class_dropouts);
+            let mut student_dropouts = self.student_dropouts.get(student_id).unwrap_or_default();
+            student_dropouts.retain(|(name, term)| name != class_name || *term != term_id);
+            self.student_dropouts.insert(student_id, &student_dropouts);
+        }
+
+        //put a student at the end of the waitlist of a class and return their position
+        fn join_waitlist(&mut self, class_name: &String, term_id: TermId, student_id: AccountId) -> u32 {
+            let mut waitlist = self.waitlists.get((class_name, term_id)).unwrap_or_default();
+            waitlist.push(student_id);
+            self.waitlists.insert((class_name, term_id), &waitlist);
+            let mut student_waitlists = self.student_waitlists.get(student_id).unwrap_or_default();
+            student_waitlists.push((class_name.clone(), term_id));
+            self.student_waitlists.insert(student_id, &student_waitlists);
+            waitlist.len() as u32
+        }
+
+        //take a student off the waitlist of a class, false if they were not on it
+        fn leave_waitlist(&mut self, class_name: &String, term_id: TermId, student_id: AccountId) -> bool {
+            let mut waitlist = self.waitlists.get((class_name, term_id)).unwrap_or_default();
+            if let Some(index) = waitlist.iter().position(|student| *student == student_id) {
+                waitlist.remove(index);
+                self.waitlists.insert((class_name, term_id), &waitlist);
+                self.remove_student_waitlist(student_id, class_name, term_id);
+                true
+            } else {
+                false
+            }
+        }
+
+        //forget that a student waits for a class
+        fn remove_student_waitlist(&mut self, student_id: AccountId, class_name: &str, term_id: TermId) {
+            let mut student_waitlists = self.student_waitlists.get(student_id).unwrap_or_default();
+            student_waitlists.retain(|(name, term)| name.as_str() != class_name || *term != term_id);
+            self.student_waitlists.insert(student_id, &student_waitlists);
+        }
+
+        //check whether a term exists and still accepts classes and enrollments
+        fn is_term_open(&self, term_id: TermId) -> bool {
+            self.terms.get(term_id).is_some_and(|term| term.status != TermStatus::Closed)
+        }
+
+        //check whether an account can grant and revoke a role
+        fn can_administer(&self, account: AccountId, role: Role) -> bool {
+            self.is_member(Role::Admin, account) || self.is_member(self.get_role_admin(role), account)
+        }
+
+        //check whether an account holds a role with the given capability
+        fn has_capability(&self, account: AccountId, capability: Capability) -> bool {
+            capability.roles().iter().any(|role| self.is_member(*role, account))
+        }
+
+        //remove a role from an account, students are erased together with all of their records
+        fn revoke(&mut self, role: Role, account: AccountId) -> Result<()> {
+            if role == Role::Student {
+                for (class_name, term_id) in self.student_classes.get(account).unwrap_or_default().into_iter() {
+                    if let Some(mut class_info) = self.classes.get((&class_name, term_id)) {
+                        class_info.students.retain(|student| *student != account);
+                        self.classes.insert((&class_name, term_id), &class_info);
+                    }
+                    self.erase_records(account, &class_name, term_id);
+                    self.fill_seats(&class_name, term_id);
+                }
+                for (class_name, term_id) in self.student_dropouts.take(account).unwrap_or_default().into_iter() {
+                    let mut class_dropouts = self.class_dropouts.get((&class_name, term_id)).unwrap_or_default();
+                    class_dropouts.retain(|student| *student != account);
+                    self.class_dropouts.insert((&class_name, term_id), &class_dropouts);
+                    self.erase_records(account, &class_name, term_id);
+                }
+                for request_id in self.student_access_requests.take(account).unwrap_or_default().into_iter() {
+                    if let Some(request) = self.access_requests.take(request_id) {
+                        self.pending_access_requests.remove((account, request.grantee));
+                    }
+                }
+                self.pending_request_counts.remove((account, true));
+                self.pending_request_counts.remove((account, false));
+                for (class_name, term_id) in self.student_waitlists.take(account).unwrap_or_default().into_iter() {
+                    let mut waitlist = self.waitlists.get((&class_name, term_id)).unwrap_or_default();
+                    waitlist.retain(|student| *student != account);
+                    self.waitlists.insert((&class_name, term_id), &waitlist);
+                }
+                self.student_classes.remove(account);
+                self.student_appeals.remove(account);
+                self.accessstudents.remove(account);
+                self.student_status.remove(account);
+            }
+
+            self.remove_member(role, account);
+            if role == Role::Admin {
+                self.admin_removals.remove(account);
+            }
+            match role {
+                Role::Admin => Self::env().emit_event(AdminRemoved { admin: account }),
+                Role::Teacher => Self::env().emit_event(TeacherRemoved { teacher: account }),
+                Role::Student => Self::env().emit_event(StudentRemoved { student: account }),
+                _ => Self::env().emit_event(RoleRevoked { role, account, sender: Self::env().caller() }),
+            }
+            Ok(())
+        }
+
+        //add an account to a role
+        fn grant(&mut self, role: Role, account: AccountId) -> Result<()> {
+            //only accounts without the role can be granted it
+            if self.insert_member(role, account) {
+                match role {
+                    Role::Admin => Self::env().emit_event(AdminAdded { admin: account }),
+                    Role::Teacher => Self::env().emit_event(TeacherAdded { teacher: account }),
+                    Role::Student => {
+                        //initate access list with student in it 
+                        self.accessstudents.insert(account, &[AccessGrant { grantee: account, expires_at: None, scope: AccessScope::All }].to_vec());
+                        Self::env().emit_event(StudentAdded { student: account });
+                    }
+                    _ => Self::env().emit_event(RoleGranted { role, account, sender: Self::env().caller() }),
+                }
+                Ok(())
+            } else {
+                Err(Error::RoleAlreadyGranted)
+            }
+        }
+
+        //archive a class offering, remembering the status it had
+        fn archive_class(&mut self, class_name: String, term_id: TermId) -> Result<()> {
+            let mut class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+            if class_info.status == ClassStatus::Archived {
+                return Err(Error::ClassArchived)
+            }
+
+            self.archived_classes.insert((&class_name, term_id), &class_info.status);
+            class_info.status = ClassStatus::Archived;
+            self.classes.insert((&class_name, term_id), &class_info);
+            Self::env().emit_event(ClassStatusChanged { class_name, term_id, status: ClassStatus::Archived });
+            Ok(())
+        }
+
+        //erase an archived class offering together with its grades
+        fn purge_class(&mut self, class_name: String, term_id: TermId) -> Result<()> {
+            let class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+            if class_info.status != ClassStatus::Archived {
+                return Err(Error::NotArchived)
+            }
+
+            for student in class_info.students.iter() {
+                self.remove_student_class(*student, &class_name, term_id);
+                self.erase_records(*student, &class_name, term_id);
+            }
+            for student in self.class_dropouts.take((&class_name, term_id)).unwrap_or_default().into_iter() {
+                let mut student_dropouts = self.student_dropouts.get(student).unwrap_or_default();
+                student_dropouts.retain(|(name, term)| *name != class_name || *term != term_id);
+                self.student_dropouts.insert(student, &student_dropouts);
+                self.erase_records(student, &class_name, term_id);
+            }
+            self.components.remove((&class_name, term_id));
+            self.class_scales.remove((&class_name, term_id));
+            self.class_teachers.remove((&class_name, term_id));
+            self.class_assistants.remove((&class_name, term_id));
+            self.archived_classes.remove((&class_name, term_id));
+            self.class_capacities.remove((&class_name, term_id));
+            for student in self.waitlists.take((&class_name, term_id)).unwrap_or_default().into_iter() {
+                self.remove_student_waitlist(student, &class_name, term_id);
+            }
+            self.classes.remove((&class_name, term_id));
+
+            if let Some(index) = self.class_list.iter().position(|(name, term)| *name == class_name && *term == term_id) {
+                self.class_list.remove(index);
+            }
+
+            Self::env().emit_event(ClassRemoved { class_name, term_id });
+            Ok(())
+        }
+
+        //archive an active student as withdrawn, they stay enrolled until they are purged
+        fn archive_student(&mut self, student_id: AccountId) -> Result<()> {
+            if !self.is_active_student(student_id) {
+                return Err(Error::StudentNotActive)
+            }
+            self.set_status(student_id, StudentStatus::Withdrawn);
+            Ok(())
+        }
+
+        //store the status of a student
+        fn set_status(&mut self, student_id: AccountId, status: StudentStatus) {
+            self.student_status.insert(student_id, &status);
+            Self::env().emit_event(StudentStatusChanged { student: student_id, status });
+        }
+
+        //erase an archived student together with all of their records
+        fn purge_student(&mut self, student_id: AccountId) -> Result<()> {
+            if !self.is_member(Role::Student, student_id) {
+                Err(Error::StudentNotRegistered)
+            } else if self.is_active_student(student_id) {
+                Err(Error::NotArchived)
+            } else {
+                self.revoke(Role::Student, student_id)
+            }
+        }
+
+        //erase the grades and appeals of a student in a class offering
+        fn erase_records(&mut self, student_id: AccountId, class_name: &String, term_id: TermId) {
+            self.grades.remove((student_id, class_name, term_id));
+            self.score_history.remove((student_id, class_name, term_id));
+            self.final_grades.remove((student_id, class_name, term_id));
+
+            let mut appeal_ids = self.student_appeals.get(student_id).unwrap_or_default();
+            appeal_ids.retain(|appeal_id| {
+                if self.appeals.get(appeal_id).is_some_and(|appeal| appeal.class_name == *class_name && appeal.term_id == term_id) {
+                    self.appeals.remove(appeal_id);
+                    false
+                } else {
+                    true
+                }
+            });
+            self.student_appeals.insert(student_id, &appeal_ids);
+        }
+
+        //finalized classes are completed, archived classes when they were finalized before
+        fn is_completed(&self, class_name: &String, term_id: TermId, class_info: &ClassInfo) -> bool {
+            class_info.status == ClassStatus::Finalized || (class_info.status == ClassStatus::Archived && self.archived_classes.get((class_name, term_id)) == Some(ClassStatus::Finalized))
+        }
+
+        //students without a stored status are active
+        fn is_active_student(&self, student_id: AccountId) -> bool {
+            self.student_status.get(student_id).unwrap_or(StudentStatus::Active) == StudentStatus::Active
+        }
+
+        //add a student to a class with an empty list of scores
+        fn enroll(&mut self, class_name: &String, term_id: TermId, class_info: &mut ClassInfo, student_id: AccountId) {
+            class_info.students.push(student_id);
+
+            self.classes.insert((class_name, term_id), class_info);
+            self.add_student_class(student_id, class_name, term_id);
+            //a returning student finds their score history under the class again
+            if self.score_history.contains((&student_id, class_name, term_id)) {
+                self.remove_dropout(student_id, class_name, term_id);
+            }
+
+            self.grades.insert((&student_id, class_name, term_id), &Vec::<Score>::new());
+            Self::env().emit_event(StudentEnrolled { class_name: class_name.clone(), term_id, student: student_id });
+        }
+
+        //check whether a class has a seat left
+        fn has_free_seat(&self, class_name: &String, term_id: TermId, class_info: &ClassInfo) -> bool {
+            self.class_capacities.get((class_name, term_id)).is_none_or(|capacity| (class_info.students.len() as u32) < capacity)
+        }
+
+        //enroll students from the front of the waitlist while an open class has free seats
+        fn fill_seats(&mut self, class_name: &String, term_id: TermId) {
+            let mut class_info = if let Some(class_info) = self.classes.get((class_name, term_id)) { class_info } else { return };
+            if class_info.status != ClassStatus::Open || !self.is_term_open(term_id) {
+                return
+            }
+
+            let mut waitlist = self.waitlists.get((class_name, term_id)).unwrap_or_default();
+            while !waitlist.is_empty() && self.has_free_seat(class_name, term_id, &class_info) {
+                let student_id = waitlist.remove(0);
+                self.remove_student_waitlist(student_id, class_name, term_id);
+                //students who were removed or archived while waiting lose their place
+                if self.is_member(Role::Student, student_id) && self.is_active_student(student_id) {
+                    self.enroll(class_name, term_id, &mut class_info, student_id);
+                }
+            }
+            self.waitlists.insert((class_name, term_id), &waitlist);
+        }
+
+        //carry out the action of a proposal once enough current admins approved it
+        fn execute_if_approved(&mut self, proposal: &mut Proposal) -> Result<()> {
+            //while paused only unpausing and upgrades go through
+            if !matches!(proposal.action, AdminAction::Unpause | AdminAction::Upgrade(_)) {
+                self.ensure_not_paused()?;
+            }
+            let approvals = proposal.approvals.iter().filter(|admin| self.is_member(Role::Admin, **admin)).count() as u32;
+            if approvals < self.admin_threshold {
+                return Ok(())
+            }
+            //ownership only moves with the approval of the owner, who must still be an admin
+            if matches!(proposal.action, AdminAction::TransferOwnership(_)) && !(proposal.approvals.contains(&self.owner) && self.is_member(Role::Admin, self.owner)) {
+                return Ok(())
+            }
+
+            match proposal.action.clone() {
+                AdminAction::AddAdmin(account) => self.grant(Role::Admin, account)?,
+                AdminAction::RemoveAdmin(account) => {
+                    //other admins can not remove the owner
+                    if account == self.owner {
+                        return Err(Error::OwnerNotRemovable)
+                    }
+                    self.check_admin_removal(account)?;
+                    self.revoke(Role::Admin, account)?
+                }
+                AdminAction::RemoveStudent(account) => {
+                    if self.is_member(Role::Student, account) {
+                        self.archive_student(account)?
+                    } else {
+                        return Err(Error::StudentNotRegistered)
+                    }
+                }
+                AdminAction::RemoveClass(class_name, term_id) => self.archive_class(class_name, term_id)?,
+                AdminAction::PurgeStudent(account) => self.purge_student(account)?,
+                AdminAction::PurgeClass(class_name, term_id) => self.purge_class(class_name, term_id)?,
+                AdminAction::SetThreshold(threshold) => {
+                    //the threshold must be reachable by the current admins
+                    if threshold >= 1 && threshold <= self.member_count(Role::Admin) {
+                        self.admin_threshold = threshold;
+                        Self::env().emit_event(AdminThresholdChanged { threshold });
+                    } else {
+                        return Err(Error::InvalidThreshold)
+                    }
+                }
+                AdminAction::Upgrade(code_hash) => self.set_code(code_hash)?,
+                AdminAction::Unpause => self.resume()?,
+                AdminAction::TransferOwnership(new_owner) => self.offer_ownership(new_owner),
+                AdminAction::SetStudentStatus(account, status) => {
+                    if self.is_member(Role::Student, account) {
+                        self.set_status(account, status)
+                    } else {
+                        return Err(Error::StudentNotRegistered)
+                    }
+                }
+                AdminAction::UnenrollStudent(class_name, term_id, account) => self.unenroll_and_fill(class_name, term_id, account)?,
+            }
+            proposal.status = ProposalStatus::Executed;
+            Self::env().emit_event(ProposalExecuted { proposal_id: proposal.id });
+            Ok(())
+        }
+
+        //changes are rejected while the contract is paused
+        fn ensure_not_paused(&self) -> Result<()> {
+            if self.is_paused() {
+                Err(Error::Paused)
+            } else {
+                Ok(())
+            }
+        }
+
+        //end a pause
+        fn resume(&mut self) -> Result<()> {
+            if !self.is_paused() {
+                return Err(Error::NotPaused)
+            }
+            self.paused.set(&false);
+            Self::env().emit_event(Unpaused { account: Self::env().caller() });
+            Ok(())
+        }
+
+        //switch to new contract code
+        fn set_code(&mut self, code_hash: Hash) -> Result<()> {
+            Self::env().set_code_hash(&code_hash).map_err(|_| Error::UpgradeFailed)?;
+            Self::env().emit_event(Upgraded { code_hash });
+            Ok(())
+        }
+
+        //remember the account ownership was offered to
+        fn offer_ownership(&mut self, new_owner: AccountId) {
+            self.pending_owner = Some(new_owner);
+            Self::env().emit_event(OwnershipTransferStarted { previous_owner: self.owner, new_owner });
+        }
+
+        //the account must be an admin and enough admins must remain to reach the minimum and the threshold
+        fn check_admin_removal(&self, admin_id: AccountId) -> Result<()> {
+            if !self.is_member(Role::Admin, admin_id) {
+                Err(Error::NotAdmin)
+            } else if self.member_count(Role::Admin) <= MIN_ADMINS.max(self.admin_threshold) {
+                Err(Error::TooFewAdmins)
+            } else {
+                Ok(())
+            }
+        }
+
+        //unenroll a student and give the free seat to the waitlist
+        fn unenroll_and_fill(&mut self, class_name: String, term_id: TermId, student_id: AccountId) -> Result<()> {
+            self.unenroll(class_name.clone(), term_id, student_id)?;
+            self.fill_seats(&class_name, term_id);
+            Ok(())
+        }
+
+        //remove a student and their grades from a class, the score history stays until a purge
+        fn unenroll(&mut self, class_name: String, term_id: TermId, student_id: AccountId) -> Result<()> {
+            let mut class_info = if let Some(class_info) = self.classes.get((&class_name, term_id)) { class_info } else { return Err(Error::ClassNotFound) };
+
+            if !self.is_member(Role::Student, student_id) {
+                Err(Error::StudentNotRegistered)
+            } else if !class_info.students.contains(&student_id) {
+                Err(Error::StudentNotEnrolled)
+            } else if class_info.status == ClassStatus::Finalized {
+                Err(Error::ClassFinalized)
+            } else if class_info.status == ClassStatus::Archived {
+                Err(Error::ClassArchived)
+            } else {
+
+                if let Some(index) = class_info.students.iter().position(|x| *x == student_id) {
+                    class_info.students.remove(index);
+                }
+
+                self.classes.insert((&class_name, term_id), &class_info);
+                self.remove_student_class(student_id, &class_name, term_id);
+
+                self.grades.take((&student_id, &class_name, term_id));
+                self.final_grades.remove((&student_id, &class_name, term_id));
+                if self.score_history.contains((&student_id, &class_name, term_id)) {
+                    self.add_dropout(student_id, &class_name, term_id);
+                }
+                Self::env().emit_event(StudentUnenrolled { class_name, term_id, student: student_id });
+                Ok(())
+            }
+        }
+
+        //check whether an account holds a role
+        fn is_member(&self, role: Role, account: AccountId) -> bool {
+            self.role_members.contains((role, account))
+        }
+
+        //number of accounts holding a role
+        fn member_count(&self, role: Role) -> u32 {
+            self.role_count.get(role).unwrap_or_default()
+        }
+
+        //all accounts holding a role, in index order
+        fn members(&self, role: Role) -> Vec<AccountId> {
+            (0..self.member_count(role)).filter_map(|position| self.role_index.get((role, position))).collect()
+        }
+
+        //add an account to a role, returns false if it already holds the role
+        fn insert_member(&mut self, role: Role, account: AccountId) -> bool {
+            if self.is_member(role, account) {
+                return false
+            }
+            let count = self.member_count(role);
+            self.role_members.insert((role, account), &count);
+            self.role_index.insert((role, count), &account);
+            self.role_count.insert(role, &(count + 1));
+            true
+        }
+
+        //remove an account from a role by moving the last member into its slot, returns false if it did not hold the role
+        fn remove_member(&mut self, role: Role, account: AccountId) -> bool {
+            let position = if let Some(position) = self.role_members.take((role, account)) { position } else { return false };
+            let last = self.member_count(role) - 1;
+            if position != last {
+                if let Some(moved) = self.role_index.get((role, last)) {
+                    self.role_index.insert((role, position), &moved);
+                    self.role_members.insert((role, moved), &position);
+                }
+            }
+            self.role_index.remove((role, last));
+            self.role_count.insert(role, &last);
+            true
+        }
+
+        
+
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        type Event = <Transcipt as ::ink::reflect::ContractEventBase>::Type;
+
+        // We define some helper Accounts to make our tests more readable
+        fn default_accounts() -> ink::env::test::DefaultAccounts<Environment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+
+        fn alice() -> AccountId {
+            default_accounts().alice
+        }
+
+        fn bob() -> AccountId {
+            default_accounts().bob
+        }
+
+        fn charlie() -> AccountId {
+            default_accounts().charlie
+        }
+
+        fn django() -> AccountId {
+            default_accounts().django
+        }
+
+        fn eve() -> AccountId {
+            default_accounts().eve
+        }
+
+        fn frank() -> AccountId {
+            default_accounts().frank
+        }
+
+        fn grantees(contract: &Transcipt, student: AccountId) -> Vec<AccountId> {
+            contract.accessstudents.get(student).unwrap_or_default().iter().map(|grant| grant.grantee).collect()
+        }
+
+        fn homework(points: u32) -> Score {
+            Score { component: "Homework".to_string(), points }
+        }
+
+
+        #[ink::test]
+        fn new_works() {
+            let contract = Transcipt::new();
+            assert_eq!(contract.get_admins(), [alice()] );
+        }
+
+        #[ink::test]
+        fn add_teacher_works() {
+            
+            let mut contract = Transcipt::new();
+            assert!(contract.add_teacher(bob()).is_ok());
+            assert!(contract.add_teacher(bob()).is_err());
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_err());
+            assert_eq!(contract.get_teachers(), [bob()] );
+            
+            
+        }
+
+        #[ink::test]
+        fn add_students_works() {
+            let mut contract = Transcipt::new();
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_student(bob()).is_err());
+            assert_eq!(contract.get_students(), [bob()] );
+            assert_eq!(grantees(&contract, bob()), [bob()].to_vec());
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.add_student(eve()).is_err());
+
+        }
+
+        #[ink::test]
+        fn add_admins_works() {
+            let mut contract = Transcipt::new();
+            assert!(contract.add_admins(bob()).is_ok());
+            assert!(contract.add_admins(bob()).is_err());
+            assert_eq!(contract.get_admins(), [alice(), bob()] );
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.add_admins(frank()).is_err());
+        }
+
+        #[ink::test]
+        fn add_classes_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_err());
+            assert!(contract.add_classes("CS51".to_string(), term, alice(), [eve()].to_vec(), 3, None).is_err());
+            assert!(contract.add_classes("CS51".to_string(), term, eve(), [bob()].to_vec(), 3, None).is_err());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((alice(), [bob()].to_vec())));
+            assert!(contract.class_list.contains(&("CS50".to_string(), term)));
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_err());
+
+        }
+
+        #[ink::test]
+        fn add_score_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), term, eve(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS51".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 2).is_ok());
+            assert_eq!(contract.grades.get((bob(), "CS50".to_string(), term)),Some([homework(2)].to_vec()));
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()).unwrap(),[homework(2)].to_vec());
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 3).is_ok());
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()).unwrap(),[homework(2), homework(3)].to_vec());
+            assert!(contract.add_score("CS51".to_string(), term, bob(), "Homework".to_string(), 3).is_err());
+        }
+
+
+        #[ink::test]
+        fn add_accessstudents_works_1() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 2).is_ok());
+            assert_eq!(grantees(&contract, bob()), [bob()].to_vec());
+            assert!(contract.add_accessstudents(bob(), frank()).is_err());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.add_accessstudents(bob(), frank()).is_ok());
+            assert_eq!(grantees(&contract, bob()), [bob(), frank()].to_vec());
+            assert!(contract.add_accessstudents(bob(), frank()).is_err());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.request_access(bob(), charlie(), AccessScope::All, None).is_ok());
+
+            
+        }
+
+        #[ink::test]
+        fn add_accessstudents_works_2() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob()].to_vec(), 3, None).is_ok());
+            assert_eq!(grantees(&contract, bob()), [bob()].to_vec());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.add_accessstudents(bob(), frank()).is_ok());
+            assert_eq!(grantees(&contract, bob()), [bob(), frank()].to_vec());
+            assert!(contract.add_accessstudents(bob(), frank()).is_err());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_teacher(alice()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.add_accessstudents(bob(), charlie()).is_err());
+            assert!(contract.request_access(bob(), charlie(), AccessScope::All, None).is_err());
+
+            
+        }
+
+        #[ink::test]
+        fn add_access_grades_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), term, eve(), [alice()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_classes("CS52".to_string(), term, eve(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 2).is_ok());
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()).unwrap(),[homework(2)].to_vec());
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert_eq!(contract.reassign_classes(alice(), eve()), Ok(1));
+            assert!(contract.remove_teacher(alice()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert_eq!(contract.access_grades("CS51".to_string(), term, alice()).unwrap(),[].to_vec());
+            assert!(contract.access_grades("CS52".to_string(), term, bob()).is_err());
+            
+        }
+
+        #[ink::test]
+        fn remove_access_grades_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), term, eve(), [alice()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_teacher(alice()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert_eq!(contract.access_grades("CS51".to_string(), term, alice()).unwrap(),[].to_vec());
+            assert!(contract.remove_accessstudents(alice(), alice()).is_err());
+            assert!(contract.access_grades("CS51".to_string(), term, alice()).is_ok());
+            
+        } 
+
+        #[ink::test]
+        fn remove_classes_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_student(eve()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), term, alice(), [eve()].to_vec(), 3, None).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((alice(), [bob()].to_vec())));
+            assert!(contract.class_list.contains(&("CS50".to_string(), term)));
+            assert!(contract.remove_classes("CS50".to_string(), term).is_ok());
+            assert!(contract.class_list.contains(&("CS50".to_string(), term)));
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).unwrap().status, ClassStatus::Archived);
+            assert_eq!(contract.remove_classes("CS50".to_string(), term), Err(Error::ClassArchived));
+            assert!(contract.class_list.contains(&("CS51".to_string(), term)));
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.remove_classes("CS51".to_string(), term).is_err());
+
+        }
+
+        #[ink::test]
+        fn enroll_unenroll_student_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_student(eve()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((alice(), [bob()].to_vec())));
+            assert!(contract.enroll_student("CS50".to_string(), term, eve()).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((alice(), [bob(), eve()].to_vec())));
+            assert!(contract.enroll_student("CS50".to_string(), term, eve()).is_err());
+            assert!(contract.enroll_student("CS50".to_string(), term, charlie()).is_err());
+            assert!(contract.unenroll_student("CS50".to_string(), term, eve()).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((alice(), [bob()].to_vec())));
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.enroll_student("CS50".to_string(), term, eve()).is_err());
+
+        }
+
+        #[ink::test]
+        fn change_teacher_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((alice(), [bob()].to_vec())));
+            assert!(contract.change_teacher("CS50".to_string(), term, eve()).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((eve(), [bob()].to_vec())));
+            assert!(contract.change_teacher("CS50".to_string(), term, charlie()).is_err());
+            assert!(contract.change_teacher("CS50".to_string(), term, bob()).is_err());
+
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.change_teacher("CS50".to_string(), term, alice()).is_err());
+
+        }
+
+        #[ink::test]
+        fn remove_teacher_works() {
+            
+            let mut contract = Transcipt::new();
+            assert!(contract.add_teacher(bob()).is_ok());
+            assert!(contract.add_teacher(charlie()).is_ok());
+            assert_eq!(contract.get_teachers(), [bob(), charlie()] );
+            assert!(contract.remove_teacher(charlie()).is_ok());
+            assert_eq!(contract.get_teachers(), [bob()] );
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.remove_teacher(charlie()).is_err());
+            
+            
+        }
+
+        #[ink::test]
+        fn remove_student_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob(), eve()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((alice(), [bob(), eve()].to_vec())));
+          
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 2).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, eve(), "Homework".to_string(), 3).is_ok());
+            assert_eq!(contract.grades.get((bob(), "CS50".to_string(), term)),Some([homework(2)].to_vec()));
+            assert_eq!(contract.grades.get((eve(), "CS50".to_string(), term)),Some([homework(3)].to_vec()));
+
+            assert!(contract.remove_student(bob()).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((alice(), [bob(), eve()].to_vec())));
+            assert_eq!(contract.grades.get((bob(), "CS50".to_string(), term)),Some([homework(2)].to_vec()));
+            assert_eq!(contract.grades.get((eve(), "CS50".to_string(), term)),Some([homework(3)].to_vec()));
+            assert_eq!(contract.get_student_status(bob()), Some(StudentStatus::Withdrawn));
+
+                        
+
+            assert!(contract.add_admins(charlie()).is_ok());
+            assert!(contract.remove_admins(alice()).is_ok());
+            assert!(contract.remove_student(eve()).is_err());
+
+        }
+
+
+
+        #[ink::test]
+        fn terms_work() {
+            let mut contract = Transcipt::new();
+            let fall_2025 = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            let fall_2026 = contract.add_term("Fall 2026".to_string(), 200, 300).unwrap();
+            assert!(contract.add_term("Spring 2026".to_string(), 150, 150).is_err());
+            assert_eq!(contract.get_terms().len(), 2);
+            assert_eq!(contract.get_term(fall_2026).unwrap().name, "Fall 2026");
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+
+            //the same class can be offered in different terms
+            assert!(contract.add_classes("CS50".to_string(), fall_2025, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), fall_2025, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), fall_2026, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), fall_2026, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), fall_2025, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), 7, alice(), [bob()].to_vec(), 3, None).is_err());
+            assert!(contract.add_score("CS50".to_string(), fall_2025, bob(), "Homework".to_string(), 2).is_ok());
+            assert!(contract.add_score("CS50".to_string(), fall_2026, bob(), "Homework".to_string(), 3).is_ok());
+            assert_eq!(contract.access_grades("CS50".to_string(), fall_2025, bob()).unwrap(),[homework(2)].to_vec());
+            assert_eq!(contract.access_grades("CS50".to_string(), fall_2026, bob()).unwrap(),[homework(3)].to_vec());
+            assert_eq!(contract.access_term_grades(fall_2025, bob()).unwrap(), [("CS50".to_string(), [homework(2)].to_vec()), ("CS51".to_string(), [].to_vec())].to_vec());
+
+            //terms only move forward and closed terms take no new classes
+            assert!(contract.set_term_status(fall_2025, TermStatus::Active).is_ok());
+            assert!(contract.set_term_status(fall_2025, TermStatus::Planned).is_err());
+            assert!(contract.set_term_status(fall_2025, TermStatus::Closed).is_ok());
+            assert_eq!(contract.get_term(fall_2025).unwrap().status, TermStatus::Closed);
+            assert!(contract.add_classes("CS52".to_string(), fall_2025, alice(), [bob()].to_vec(), 3, None).is_err());
+            assert!(contract.add_classes("CS52".to_string(), fall_2026, alice(), [bob()].to_vec(), 3, None).is_ok());
+        }
+
+        #[ink::test]
+        fn weighted_components_work() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_student(eve()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob(), eve()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 30, 10).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Midterm".to_string(), 30, 100).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 10, 10).is_err());
+            assert!(contract.add_component("CS50".to_string(), term, "Final".to_string(), 41, 100).is_err());
+            assert!(contract.add_component("CS50".to_string(), term, "Final".to_string(), 40, 0).is_err());
+            assert!(contract.add_component("CS50".to_string(), term, "Final".to_string(), 40, 100).is_ok());
+            assert_eq!(contract.get_components("CS50".to_string(), term).len(), 3);
+
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 8).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 10).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 11).is_err());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Quiz".to_string(), 1).is_err());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Midterm".to_string(), 70).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Final".to_string(), 85).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, eve(), "Final".to_string(), 50).is_ok());
+
+            //30% * 90% + 30% * 70% + 40% * 85%, missing components count as zero
+            assert_eq!(contract.final_percentage("CS50".to_string(), term, bob()), Ok(8200));
+            assert_eq!(contract.final_percentage("CS50".to_string(), term, eve()), Ok(2000));
+            assert!(contract.final_percentage("CS51".to_string(), term, eve()).is_err());
+
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.final_percentage("CS50".to_string(), term, bob()).is_err());
+            assert!(contract.add_component("CS50".to_string(), term, "Quiz".to_string(), 0, 10).is_err());
+        }
+
+        #[ink::test]
+        fn final_grades_work() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_student(eve()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob(), eve()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 100).is_ok());
+            assert!(contract.add_component("CS51".to_string(), term, "Homework".to_string(), 100, 100).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 91).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, eve(), "Homework".to_string(), 59).is_ok());
+            assert!(contract.add_score("CS51".to_string(), term, bob(), "Homework".to_string(), 61).is_ok());
+            assert_eq!(contract.get_final_grade("CS50".to_string(), term, bob()), Ok(None));
+
+            //default scale
+            assert!(contract.compute_final_grades("CS50".to_string(), term).is_ok());
+            assert_eq!(contract.get_final_grade("CS50".to_string(), term, bob()), Ok(Some(FinalGrade { percentage: 9100, grade: Grade::A })));
+            assert_eq!(contract.get_final_grade("CS50".to_string(), term, eve()), Ok(Some(FinalGrade { percentage: 5900, grade: Grade::F })));
+
+            //scales must be decreasing and end at zero
+            assert!(contract.set_class_scale("CS51".to_string(), term, [(6000, Grade::Pass)].to_vec()).is_err());
+            assert!(contract.set_class_scale("CS51".to_string(), term, [(6000, Grade::Pass), (7000, Grade::Fail), (0, Grade::Fail)].to_vec()).is_err());
+            assert!(contract.set_class_scale("CS51".to_string(), term, [(10001, Grade::Pass), (0, Grade::Fail)].to_vec()).is_err());
+            assert!(contract.set_class_scale("CS51".to_string(), term, [(6000, Grade::Pass), (0, Grade::Fail)].to_vec()).is_ok());
+            assert!(contract.compute_final_grades("CS51".to_string(), term).is_ok());
+            assert_eq!(contract.get_final_grade("CS51".to_string(), term, bob()).unwrap().unwrap().grade, Grade::Pass);
+
+            //plus/minus scale for the whole institution
+            assert!(contract.set_default_scale([(9300, Grade::A), (9000, Grade::AMinus), (8700, Grade::BPlus), (0, Grade::F)].to_vec()).is_ok());
+            assert!(contract.compute_final_grades("CS50".to_string(), term).is_ok());
+            assert_eq!(contract.get_final_grade("CS50".to_string(), term, bob()).unwrap().unwrap().grade, Grade::AMinus);
+            assert_eq!(contract.get_grading_scale("CS51".to_string(), term).len(), 2);
+
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.compute_final_grades("CS50".to_string(), term).is_err());
+            assert!(contract.get_final_grade("CS50".to_string(), term, bob()).is_err());
+            assert!(contract.set_default_scale([(0, Grade::Pass)].to_vec()).is_err());
+        }
+
+        #[ink::test]
+        fn gpa_works() {
+            let mut contract = Transcipt::new();
+            let fall = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            let spring = contract.add_term("Spring 2026".to_string(), 200, 300).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            //a student counts once per class
+            assert_eq!(contract.add_classes("CS50".to_string(), fall, alice(), [bob(), bob()].to_vec(), 4, None), Err(Error::StudentAlreadyEnrolled));
+            for (class_name, term, credits, points) in [("CS50", fall, 4, 95), ("CS51", fall, 3, 75), ("CS52", spring, 3, 85), ("CS53", spring, 1, 99)] {
+                assert!(contract.add_classes(class_name.to_string(), term, alice(), [bob()].to_vec(), credits, None).is_ok());
+                assert!(contract.add_component(class_name.to_string(), term, "Homework".to_string(), 100, 100).is_ok());
+                assert!(contract.add_score(class_name.to_string(), term, bob(), "Homework".to_string(), points).is_ok());
+            }
+            assert!(contract.set_class_scale("CS53".to_string(), spring, [(6000, Grade::Pass), (0, Grade::Fail)].to_vec()).is_ok());
+
+            //only grades of finalized classes count
+            assert_eq!(contract.get_gpa(bob(), fall).unwrap(), GpaReport { term_gpa: 0, term_credits: 0, cumulative_gpa: 0, cumulative_credits: 0 });
+            for (class_name, term) in [("CS50", fall), ("CS51", fall), ("CS52", spring), ("CS53", spring)] {
+                assert!(contract.compute_final_grades(class_name.to_string(), term).is_ok());
+            }
+            assert_eq!(contract.get_gpa(bob(), fall).unwrap(), GpaReport { term_gpa: 0, term_credits: 0, cumulative_gpa: 0, cumulative_credits: 0 });
+            for (class_name, term) in [("CS50", fall), ("CS51", fall), ("CS52", spring), ("CS53", spring)] {
+                assert!(contract.start_grading(class_name.to_string(), term).is_ok());
+                assert!(contract.finalize_class(class_name.to_string(), term).is_ok());
+            }
+
+            //(4 * 4.00 + 3 * 2.00) / 7
+            assert_eq!(contract.get_gpa(bob(), fall).unwrap(), GpaReport { term_gpa: 314, term_credits: 7, cumulative_gpa: 314, cumulative_credits: 7 });
+            //pass/fail classes have no grade points
+            assert_eq!(contract.get_gpa(bob(), spring).unwrap(), GpaReport { term_gpa: 300, term_credits: 3, cumulative_gpa: 310, cumulative_credits: 10 });
+            assert!(contract.get_gpa(bob(), 9).is_err());
+
+            assert!(contract.set_grade_points([(Grade::A, 400), (Grade::A, 300)].to_vec()).is_err());
+            assert_eq!(contract.set_grade_points([(Grade::A, MAX_GRADE_POINTS + 1)].to_vec()), Err(Error::InvalidGradePoints));
+            assert!(contract.set_grade_points([(Grade::A, MAX_GRADE_POINTS)].to_vec()).is_ok());
+            assert_eq!(contract.get_gpa(bob(), fall).unwrap().cumulative_gpa, MAX_GRADE_POINTS);
+            assert!(contract.set_grade_points([(Grade::A, 400), (Grade::C, 250), (Grade::Pass, 400)].to_vec()).is_ok());
+            assert_eq!(contract.get_grade_points().len(), 3);
+            assert_eq!(contract.get_gpa(bob(), fall).unwrap().term_gpa, 335);
+
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.get_gpa(bob(), spring).is_ok());
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.get_gpa(bob(), spring).is_err());
+            assert!(contract.set_grade_points([].to_vec()).is_err());
+        }
+
+        #[ink::test]
+        fn get_transcript_works() {
+            let mut contract = Transcipt::new();
+            let fall = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            let spring = contract.add_term("Spring 2026".to_string(), 200, 300).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_student(charlie()).is_ok());
+            assert!(contract.add_classes("CS52".to_string(), spring, eve(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), fall, alice(), [bob(), charlie()].to_vec(), 4, None).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), fall, alice(), [charlie()].to_vec(), 4, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), fall, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.add_score("CS50".to_string(), fall, bob(), "Homework".to_string(), 9).is_ok());
+            assert!(contract.start_grading("CS50".to_string(), fall).is_ok());
+            assert!(contract.finalize_class("CS50".to_string(), fall).is_ok());
+            //provisional grades of running classes are not shown as final
+            assert!(contract.compute_final_grades("CS52".to_string(), spring).is_ok());
+
+            let transcript = contract.get_transcript(bob()).unwrap();
+            assert_eq!(transcript, [
+                TranscriptEntry {
+                    class_name: "CS50".to_string(),
+                    term_id: fall,
+                    teacher: alice(),
+                    credits: 4,
+                    scores: [homework(9)].to_vec(),
+                    final_grade: Some(FinalGrade { percentage: 9000, grade: Grade::A }),
+                    completed: true,
+                },
+                TranscriptEntry {
+                    class_name: "CS52".to_string(),
+                    term_id: spring,
+                    teacher: eve(),
+                    credits: 3,
+                    scores: [].to_vec(),
+                    final_grade: None,
+                    completed: false,
+                },
+            ].to_vec());
 
+            assert!(contract.unenroll_student("CS52".to_string(), spring, bob()).is_ok());
+            assert_eq!(contract.get_transcript(bob()).unwrap().len(), 1);
+            assert_eq!(contract.get_transcript(charlie()).unwrap().len(), 2);
 
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.get_transcript(bob()).is_ok());
+            assert!(contract.get_transcript(charlie()).is_err());
         }
 
-        #[ink(message)]
-        pub fn enroll_student(&mut self,class_name: String, student_id: AccountId) -> Result<()>{
-            if self.admins.contains(&Self::env().caller()) {
-
-                let class_info = self.classes.get(&class_name).unwrap();
-                let mut students = class_info.1;
+        #[ink::test]
+        fn class_lifecycle_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_student(charlie()).is_ok());
+            assert!(contract.add_student(frank()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob(), charlie()].to_vec(), 4, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.finalize_class("CS50".to_string(), term).is_err());
+            assert!(contract.start_grading("CS50".to_string(), term).is_ok());
+            assert!(contract.start_grading("CS50".to_string(), term).is_err());
 
-                if self.students.contains(&student_id) && !students.contains(&student_id) {
-                    
-                    students.push(student_id);
+            //enrollment and components are closed while grading
+            assert!(contract.enroll_student("CS50".to_string(), term, frank()).is_err());
+            assert_eq!(contract.add_component("CS50".to_string(), term, "Final".to_string(), 10, 10), Err(Error::ClassNotOpen));
 
-                    self.classes.insert(&class_name, &(class_info.0, students));
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 6).is_ok());
+            assert!(contract.amend_score("CS50".to_string(), term, bob(), 0, 9, "typo".to_string()).is_ok());
+            assert!(contract.amend_score("CS50".to_string(), term, bob(), 1, 9, "typo".to_string()).is_err());
+            assert!(contract.amend_score("CS50".to_string(), term, bob(), 0, 11, "typo".to_string()).is_err());
+            assert!(contract.finalize_class("CS50".to_string(), term).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).unwrap().status, ClassStatus::Finalized);
+            assert_eq!(contract.get_final_grade("CS50".to_string(), term, bob()).unwrap().unwrap().grade, Grade::A);
 
-                    self.grades.insert((&student_id, &class_name), &Vec::<u8>::new());
+            //finalized grades are frozen for the teacher
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 1).is_err());
+            assert!(contract.amend_score("CS50".to_string(), term, bob(), 0, 5, "typo".to_string()).is_err());
+            assert!(contract.compute_final_grades("CS50".to_string(), term).is_err());
 
-                    Ok(())
-                } else {{
-                    return Err(Error::InvalidInput) 
-                }}
+            //and can not be wiped by admins
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.unenroll_student("CS50".to_string(), term, bob()).is_err());
+            assert!(contract.remove_student(charlie()).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).unwrap().students, [bob(), charlie()].to_vec());
 
-            } else {
-                return Err(Error::AccessNotAllowed) 
-            }
+            //admins amend finalized grades explicitly
+            assert!(contract.amend_score("CS50".to_string(), term, bob(), 0, 5, "typo".to_string()).is_ok());
+            assert_eq!(contract.get_final_grade("CS50".to_string(), term, bob()).unwrap().unwrap(), FinalGrade { percentage: 5000, grade: Grade::F });
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()).unwrap(), [homework(5)].to_vec());
 
+            //archived grades stay readable but can no longer be amended
+            assert!(contract.remove_classes("CS50".to_string(), term).is_ok());
+            assert_eq!(contract.amend_score("CS50".to_string(), term, bob(), 0, 6, "typo".to_string()), Err(Error::ClassArchived));
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()).unwrap(), [homework(5)].to_vec());
         }
 
+        #[ink::test]
+        fn score_history_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 4, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 6).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 7).is_ok());
+            assert!(contract.amend_score("CS50".to_string(), term, bob(), 1, 8, "".to_string()).is_err());
 
-        #[ink(message)]
-        pub fn change_teacher(&mut self,class_name: String, teacher_id: AccountId) -> Result<()>{
+            ink::env::test::set_block_timestamp::<Environment>(42);
+            assert!(contract.amend_score("CS50".to_string(), term, bob(), 1, 8, "typo".to_string()).is_ok());
+            assert!(contract.amend_score("CS50".to_string(), term, bob(), 1, 9, "regraded".to_string()).is_ok());
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()).unwrap(), [homework(6), homework(9)].to_vec());
+            assert_eq!(contract.get_score_history("CS50".to_string(), term, bob()).unwrap(), [
+                Amendment { index: 1, old_points: 7, new_points: 8, amended_by: alice(), timestamp: 42, reason: "typo".to_string() },
+                Amendment { index: 1, old_points: 8, new_points: 9, amended_by: alice(), timestamp: 42, reason: "regraded".to_string() },
+            ].to_vec());
 
-            if self.admins.contains(&Self::env().caller()) {
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.get_score_history("CS50".to_string(), term, bob()).is_err());
 
-                
+            //unenrolling keeps the history until the student is purged
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.unenroll_student("CS50".to_string(), term, bob()).is_ok());
+            assert_eq!(contract.grades.get((bob(), "CS50".to_string(), term)), None);
+            assert_eq!(contract.get_score_history("CS50".to_string(), term, bob()).unwrap().len(), 2);
+            assert!(contract.enroll_student("CS50".to_string(), term, bob()).is_ok());
+            assert!(contract.unenroll_student("CS50".to_string(), term, bob()).is_ok());
+            assert_eq!(contract.student_dropouts.get(bob()).unwrap(), [("CS50".to_string(), term)].to_vec());
+            assert!(contract.remove_student(bob()).is_ok());
+            assert!(contract.propose(AdminAction::PurgeStudent(bob())).is_ok());
+            assert_eq!(contract.score_history.get((bob(), "CS50".to_string(), term)), None);
+            assert!(contract.class_dropouts.get(("CS50".to_string(), term)).unwrap().is_empty());
+        }
 
-                if self.teachers.contains(&teacher_id)  {
+        #[ink::test]
+        fn appeals_work() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_student(charlie()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob(), charlie()].to_vec(), 4, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 6).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 7).is_ok());
 
-                    let class_info = self.classes.get(&class_name).unwrap();
-                    let students = class_info.1;
-                    
+            //students appeal existing scores of their own, once at a time
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.open_appeal("CS50".to_string(), term, 2, Hash::from([1; 32])).is_err());
+            let first = contract.open_appeal("CS50".to_string(), term, 0, Hash::from([1; 32])).unwrap();
+            assert!(contract.open_appeal("CS50".to_string(), term, 0, Hash::from([1; 32])).is_err());
+            let second = contract.open_appeal("CS50".to_string(), term, 1, Hash::from([2; 32])).unwrap();
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.open_appeal("CS50".to_string(), term, 0, Hash::from([1; 32])).is_err());
+            assert!(contract.get_appeal(first).is_err());
 
-                    self.classes.insert(&class_name, &(teacher_id, students));
+            //the teacher responds, other accounts can not
+            assert!(contract.respond_appeal(first, Hash::from([3; 32])).is_err());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.respond_appeal(first, Hash::from([3; 32])).is_ok());
+            assert!(contract.respond_appeal(first, Hash::from([3; 32])).is_err());
+            assert!(contract.resolve_appeal(first, AppealOutcome::Uphold).is_err());
 
-                    Ok(())
-                } else {
-                    return Err(Error::InvalidInput) 
-                }
+            //an admin resolves
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.resolve_appeal(first, AppealOutcome::Amend(11)).is_err());
+            assert!(contract.resolve_appeal(first, AppealOutcome::Amend(9)).is_ok());
+            assert!(contract.resolve_appeal(first, AppealOutcome::Uphold).is_err());
 
-            } else {
-                return Err(Error::AccessNotAllowed) 
-            }
+            //appeals without a response wait for the teacher until the response window passed
+            assert_eq!(contract.resolve_appeal(second, AppealOutcome::Uphold), Err(Error::AppealAwaitingResponse));
+            ink::env::test::set_block_timestamp::<Environment>(APPEAL_RESPONSE_WINDOW);
+            assert!(contract.resolve_appeal(second, AppealOutcome::Uphold).is_ok());
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()).unwrap(), [homework(9), homework(7)].to_vec());
+            assert_eq!(contract.get_score_history("CS50".to_string(), term, bob()).unwrap()[0].reason, "appeal 0");
 
+            ink::env::test::set_caller::<Environment>(bob());
+            let appeal = contract.get_appeal(first).unwrap();
+            assert_eq!(appeal.status, AppealStatus::Amended);
+            assert_eq!(appeal.response_hash, Some(Hash::from([3; 32])));
+            assert_eq!(appeal.new_points, Some(9));
+            assert_eq!(contract.get_student_appeals(bob()).unwrap().iter().map(|appeal| appeal.status).collect::<Vec<_>>(), [AppealStatus::Amended, AppealStatus::Upheld].to_vec());
+            assert!(contract.get_student_appeals(charlie()).is_err());
         }
 
-        #[ink(message)]
-        pub fn remove_teacher(&mut self, teacher_id: AccountId) -> Result<()>{
+        #[ink::test]
+        fn timed_access_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob()].to_vec(), 4, None).is_ok());
+            ink::env::test::set_block_timestamp::<Environment>(10);
 
-            if self.admins.contains(&Self::env().caller()) {
-                if self.teachers.contains(&teacher_id) {
+            //the student grants access until a timestamp in the future
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.add_accessstudents_until(bob(), frank(), 10).is_err());
+            assert!(contract.add_accessstudents_until(bob(), frank(), 20).is_ok());
+            assert!(contract.add_accessstudents_until(bob(), frank(), 30).is_err());
+            assert_eq!(contract.get_access_grants(bob(), true).unwrap(), [AccessGrant { grantee: bob(), expires_at: None, scope: AccessScope::All }, AccessGrant { grantee: frank(), expires_at: Some(20), scope: AccessScope::All }].to_vec());
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_ok());
 
-                    if let Some(index) = self.teachers.iter().position(|x| *x == teacher_id) {
-                        self.teachers.remove(index);
-                    }
+            //expired grants are rejected and listed as expired
+            ink::env::test::set_block_timestamp::<Environment>(20);
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_err());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert_eq!(contract.get_access_grants(bob(), false).unwrap(), [AccessGrant { grantee: frank(), expires_at: Some(20), scope: AccessScope::All }].to_vec());
 
-                    Ok(())
-                } else {
-                    return Err(Error::InvalidInput) 
-                }
-            } else {
-                return Err(Error::AccessNotAllowed) 
-            }
+            //an expired grant can be renewed
+            assert!(contract.add_accessstudents(bob(), frank()).is_ok());
+            assert_eq!(contract.get_access_grants(bob(), false).unwrap(), [].to_vec());
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_ok());
+            assert!(contract.get_access_grants(eve(), true).is_err());
         }
 
+        #[ink::test]
+        fn scoped_access_works() {
+            let mut contract = Transcipt::new();
+            let fall = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            let spring = contract.add_term("Spring 2026".to_string(), 100, 200).unwrap();
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), fall, eve(), [bob()].to_vec(), 4, None).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), fall, eve(), [bob()].to_vec(), 4, None).is_ok());
+            assert!(contract.add_classes("CS52".to_string(), spring, eve(), [bob()].to_vec(), 4, None).is_ok());
 
-        #[ink(message)]
-        pub fn remove_student(&mut self, student_id: AccountId) -> Result<()>{
-
-            if self.admins.contains(&Self::env().caller()) {
-                if self.students.contains(&student_id) {
+            //a grant for one class only
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.add_scoped_access(bob(), frank(), AccessScope::Classes([].to_vec()), None).is_err());
+            assert!(contract.add_scoped_access(bob(), frank(), AccessScope::Classes([("CS50".to_string(), fall)].to_vec()), None).is_ok());
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.access_grades("CS50".to_string(), fall, bob()).is_ok());
+            assert!(contract.access_grades("CS51".to_string(), fall, bob()).is_err());
+            assert_eq!(contract.access_term_grades(fall, bob()).unwrap(), [("CS50".to_string(), [].to_vec())].to_vec());
+            assert_eq!(contract.get_transcript(bob()).unwrap().iter().map(|entry| entry.class_name.clone()).collect::<Vec<_>>(), ["CS50".to_string()].to_vec());
+            assert!(contract.get_gpa(bob(), fall).is_err());
 
-                    let mut student_classes = Vec::<String>::new();
-                    for class in self.class_list.iter() {
-                        if self.classes.get(class).unwrap().1.contains(&student_id) {
-                            //self.unenroll_student(class, student_id);
-                            student_classes.push((&class).to_string());
-                            //self.grades.take((student_id, &class));
-                        }
-                    }
+            //a grant for one term
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.add_scoped_access(bob(), charlie(), AccessScope::Term(spring), Some(150)).is_ok());
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.access_grades("CS52".to_string(), spring, bob()).is_ok());
+            assert!(contract.access_grades("CS50".to_string(), fall, bob()).is_err());
+            assert_eq!(contract.access_term_grades(fall, bob()).unwrap(), [].to_vec());
+            assert_eq!(contract.get_transcript(bob()).unwrap().len(), 1);
+            ink::env::test::set_block_timestamp::<Environment>(150);
+            assert!(contract.get_transcript(bob()).is_err());
+        }
 
-                    
-
-                    for class in student_classes.iter() {
-                        self.grades.take((student_id, &class));
-                        //self.unenroll_student((&class).to_string(), student_id);
-                        match self.unenroll_student((&class).to_string(), student_id) {
-                            Ok(_) => {
-                                continue
-                            }
-                            Err(e) => {
-                                return Err(e)
-                            }
-                        }
-                    }
+        #[ink::test]
+        fn access_requests_work() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob()].to_vec(), 4, None).is_ok());
 
-                    if let Some(index) = self.students.iter().position(|x| *x == student_id) {
-                        self.students.remove(index);
-                    }
+            //third parties request access for themselves, access managers for anyone
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.request_access(bob(), charlie(), AccessScope::All, None).is_err());
+            assert!(contract.request_access(eve(), frank(), AccessScope::All, None).is_err());
+            let first = contract.request_access(bob(), frank(), AccessScope::Term(term), None).unwrap();
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_err());
+            ink::env::test::set_caller::<Environment>(eve());
+            let second = contract.request_access(bob(), charlie(), AccessScope::All, None).unwrap();
 
-                    
+            //the student decides
+            assert!(contract.decide_access_request(first, true).is_err());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.decide_access_request(first, true).is_ok());
+            assert!(contract.decide_access_request(first, false).is_err());
+            assert!(contract.decide_access_request(second, false).is_ok());
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_ok());
+            assert_eq!(contract.get_access_request(first).unwrap().decided_by, Some(bob()));
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_err());
 
+            //an admin can override, which stays on record
+            let third = contract.request_access(bob(), charlie(), AccessScope::All, None).unwrap();
+            assert_eq!(contract.request_access(bob(), charlie(), AccessScope::All, None), Err(Error::RequestAlreadyPending));
+            assert!(contract.override_access_request(third).is_err());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.override_access_request(third).is_ok());
+            assert!(contract.override_access_request(second).is_err());
+            assert_eq!(contract.get_access_requests(bob()).unwrap().iter().map(|request| request.status).collect::<Vec<_>>(), [AccessRequestStatus::Approved, AccessRequestStatus::Rejected, AccessRequestStatus::Overridden].to_vec());
+            assert_eq!(contract.get_access_request(third).unwrap().decided_by, Some(alice()));
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_ok());
 
-                    Ok(())
-                } else {
-                    return Err(Error::InvalidInput) 
-                }
-            } else {
-                return Err(Error::AccessNotAllowed) 
+            //a student has a bounded number of pending requests, requests for yourself do not block access managers
+            for account in 0..MAX_PENDING_REQUESTS {
+                ink::env::test::set_caller::<Environment>(AccountId::from([account as u8 + 10; 32]));
+                assert!(contract.request_access(bob(), AccountId::from([account as u8 + 10; 32]), AccessScope::All, None).is_ok());
+            }
+            ink::env::test::set_caller::<Environment>(AccountId::from([98; 32]));
+            assert_eq!(contract.request_access(bob(), AccountId::from([98; 32]), AccessScope::All, None), Err(Error::TooManyPendingRequests));
+            ink::env::test::set_caller::<Environment>(alice());
+            let managed = contract.request_access(bob(), AccountId::from([99; 32]), AccessScope::All, None).unwrap();
+            for account in 1..MAX_PENDING_REQUESTS {
+                assert!(contract.request_access(bob(), AccountId::from([account as u8 + 100; 32]), AccessScope::All, None).is_ok());
             }
+            assert_eq!(contract.request_access(bob(), AccountId::from([98; 32]), AccessScope::All, None), Err(Error::TooManyPendingRequests));
+
+            //decided requests free their slot
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.decide_access_request(managed, false).is_ok());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.request_access(bob(), AccountId::from([99; 32]), AccessScope::All, None).is_ok());
         }
 
-        
+        #[ink::test]
+        fn self_service_revocation_works() {
+            let mut contract = Transcipt::new();
+            assert!(contract.add_student(bob()).is_ok());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.add_accessstudents(bob(), frank()).is_ok());
+            assert!(contract.add_accessstudents(bob(), charlie()).is_ok());
+            assert!(contract.add_scoped_access(bob(), eve(), AccessScope::Term(0), None).is_ok());
 
-    }
+            //students revoke grants they own but never their own access
+            assert!(contract.remove_accessstudents(bob(), bob()).is_err());
+            assert!(contract.remove_accessstudents(bob(), frank()).is_ok());
+            assert_eq!(grantees(&contract, bob()), [bob(), charlie(), eve()].to_vec());
+            assert!(contract.revoke_all_access(bob()).is_ok());
+            assert_eq!(grantees(&contract, bob()), [bob()].to_vec());
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+            //other accounts can not revoke a student's grants
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.revoke_all_access(bob()).is_err());
+            assert!(contract.remove_accessstudents(bob(), bob()).is_err());
 
-        // We define some helper Accounts to make our tests more readable
-        fn default_accounts() -> ink::env::test::DefaultAccounts<Environment> {
-            ink::env::test::default_accounts::<Environment>()
+            let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let revoked = events.iter().filter(|event| matches!(<Event as scale::Decode>::decode(&mut &event.data[..]).unwrap(), Event::AccessRevoked(_))).count();
+            assert_eq!(revoked, 3);
         }
 
+        #[ink::test]
+        fn teacher_read_policy_works() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_teacher(frank()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob()].to_vec(), 4, None).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), term, frank(), [bob()].to_vec(), 4, None).is_ok());
+            assert!(contract.grant_role(Role::Advisor, charlie()).is_ok());
+            assert_eq!(contract.get_teacher_read_policy(), TeacherReadPolicy::OwnClasses);
 
-        fn alice() -> AccountId {
-            default_accounts().alice
-        }
+            //teachers only read the classes they teach
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_ok());
+            assert!(contract.access_grades("CS51".to_string(), term, bob()).is_err());
+            assert_eq!(contract.get_transcript(bob()).unwrap().len(), 1);
+            assert!(contract.get_gpa(bob(), term).is_err());
 
-        fn bob() -> AccountId {
-            default_accounts().bob
-        }
+            //and keep access to classes they taught
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.change_teacher("CS50".to_string(), term, frank()).is_ok());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_ok());
+            ink::env::test::set_caller::<Environment>(frank());
+            assert_eq!(contract.access_term_grades(term, bob()).unwrap().len(), 2);
 
-        fn charlie() -> AccountId {
-            default_accounts().charlie
-        }
+            //advisors read everything
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.get_gpa(bob(), term).is_ok());
 
-        fn eve() -> AccountId {
-            default_accounts().eve
+            //the policy can open up every student to teachers
+            assert!(contract.set_teacher_read_policy(TeacherReadPolicy::AllStudents).is_err());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.set_teacher_read_policy(TeacherReadPolicy::AllStudents).is_ok());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.access_grades("CS51".to_string(), term, bob()).is_ok());
         }
 
-        fn frank() -> AccountId {
-            default_accounts().frank
-        }
+        #[ink::test]
+        fn admin_proposals_work() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob()].to_vec(), 4, None).is_ok());
 
+            //with a threshold of one proposals execute right away
+            let first = contract.propose(AdminAction::AddAdmin(charlie())).unwrap();
+            assert_eq!(contract.get_proposal(first).unwrap().status, ProposalStatus::Executed);
+            assert!(contract.propose(AdminAction::SetThreshold(3)).is_err());
+            assert!(contract.propose(AdminAction::SetThreshold(2)).is_ok());
+            assert_eq!(contract.get_admin_threshold(), 2);
 
-        #[ink::test]
-        fn new_works() {
-            let contract = Transcipt::new();
-            assert_eq!(contract.admins, [alice()] );
-        }
+            //sensitive actions now need two admins
+            assert!(contract.add_admins(frank()).is_err());
+            assert!(contract.remove_admins(charlie()).is_err());
+            assert!(contract.remove_student(bob()).is_err());
+            assert!(contract.remove_classes("CS50".to_string(), term).is_err());
+            assert_eq!(contract.unenroll_student("CS50".to_string(), term, bob()), Err(Error::ProposalRequired));
+            assert!(contract.add_teacher(frank()).is_ok());
 
-        #[ink::test]
-        fn add_teacher_works() {
-            
-            let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(bob()).is_ok());
-            assert!(contract.add_teacher(bob()).is_err());
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.add_teacher(eve()).is_err());
-            assert_eq!(contract.teachers, [bob()] );
-            
-            
+            let unenrollment = contract.propose(AdminAction::UnenrollStudent("CS50".to_string(), term, bob())).unwrap();
+            assert!(contract.classes.get(("CS50".to_string(), term)).unwrap().students.contains(&bob()));
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.approve_proposal(unenrollment).is_ok());
+            assert!(contract.classes.get(("CS50".to_string(), term)).unwrap().students.is_empty());
+            ink::env::test::set_caller::<Environment>(alice());
+
+            let removal = contract.propose(AdminAction::RemoveClass("CS50".to_string(), term)).unwrap();
+            assert!(contract.approve_proposal(removal).is_err());
+            assert_eq!(contract.get_proposal(removal).unwrap().status, ProposalStatus::Pending);
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.approve_proposal(removal).is_err());
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.approve_proposal(removal).is_ok());
+            assert_eq!(contract.get_proposal(removal).unwrap().status, ProposalStatus::Executed);
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).unwrap().status, ClassStatus::Archived);
+
+            //admins can not be removed below the threshold
+            let admin_removal = contract.propose(AdminAction::RemoveAdmin(alice())).unwrap();
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.approve_proposal(admin_removal).is_err());
+
+            //proposals expire after their window
+            let student_removal = contract.propose(AdminAction::RemoveStudent(bob())).unwrap();
+            ink::env::test::set_block_timestamp::<Environment>(PROPOSAL_WINDOW);
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.approve_proposal(student_removal).is_err());
+            assert_eq!(contract.get_proposal(student_removal).unwrap().status, ProposalStatus::Expired);
+            assert_eq!(contract.get_students(), [bob()]);
         }
 
         #[ink::test]
-        fn add_students_works() {
+        fn admin_removal_and_ownership_work() {
             let mut contract = Transcipt::new();
-            assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_student(bob()).is_err());
-            assert_eq!(contract.students, [bob()] );
-            assert_eq!(contract.accessstudents.get(bob()), Some([bob()].to_vec()) );
+            assert_eq!(contract.get_owner(), alice());
+            assert_eq!(contract.remove_admins(alice()), Err(Error::TooFewAdmins));
+            assert_eq!(contract.remove_admins(bob()), Err(Error::NotAdmin));
+            assert!(contract.add_admins(bob()).is_ok());
             assert!(contract.add_admins(charlie()).is_ok());
+
+            //removing another admin needs a second admin to confirm
+            assert!(contract.remove_admins(charlie()).is_ok());
+            assert_eq!(contract.get_admin_removal(charlie()), Some(alice()));
+            assert!(contract.confirm_admin_removal(charlie()).is_err());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.confirm_admin_removal(charlie()).is_ok());
+            assert_eq!(contract.get_admins(), [alice(), bob()]);
+            assert_eq!(contract.get_admin_removal(charlie()), None);
+
+            //the owner can not be removed by other admins
+            assert!(contract.remove_admins(alice()).is_err());
+
+            //ownership moves in two steps
+            assert!(contract.transfer_ownership(bob()).is_err());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.transfer_ownership(eve()).is_ok());
+            assert!(contract.accept_ownership().is_err());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.accept_ownership().is_ok());
+            assert_eq!(contract.get_owner(), eve());
+            assert_eq!(contract.get_admins(), [alice(), bob(), eve()]);
+
+            //the owner restores admins only while fewer remain than proposals need
+            ink::env::test::set_caller::<Environment>(alice());
             assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.add_student(eve()).is_err());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.remove_admins(bob()).is_ok());
+            assert!(contract.recover_admin(frank()).is_err());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert_eq!(contract.recover_admin(frank()), Err(Error::EnoughAdmins));
+            contract.remove_member(Role::Admin, eve());
+            assert!(contract.recover_admin(frank()).is_ok());
+            assert_eq!(contract.recover_admin(alice()), Err(Error::EnoughAdmins));
+            assert_eq!(contract.get_admins(), [frank()]);
 
+            //above a threshold of one ownership moves through a proposal and does not make the new owner an admin
+            ink::env::test::set_caller::<Environment>(frank());
+            assert!(contract.add_admins(bob()).is_ok());
+            assert!(contract.add_admins(eve()).is_ok());
+            assert!(contract.propose(AdminAction::SetThreshold(2)).is_ok());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert_eq!(contract.transfer_ownership(charlie()), Err(Error::ProposalRequired));
+
+            //other admins can not take ownership without the owner
+            ink::env::test::set_caller::<Environment>(frank());
+            let takeover = contract.propose(AdminAction::TransferOwnership(frank())).unwrap();
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.approve_proposal(takeover).is_ok());
+            assert_eq!(contract.get_proposal(takeover).unwrap().status, ProposalStatus::Pending);
+            ink::env::test::set_caller::<Environment>(frank());
+            assert_eq!(contract.accept_ownership(), Err(Error::AccessNotAllowed));
+            let removal = contract.propose(AdminAction::RemoveAdmin(eve())).unwrap();
+            ink::env::test::set_caller::<Environment>(bob());
+            assert_eq!(contract.approve_proposal(removal), Err(Error::OwnerNotRemovable));
+            assert_eq!(contract.get_owner(), eve());
+
+            ink::env::test::set_caller::<Environment>(frank());
+            let transfer = contract.propose(AdminAction::TransferOwnership(charlie())).unwrap();
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.approve_proposal(transfer).is_ok());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.approve_proposal(transfer).is_ok());
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.accept_ownership().is_ok());
+            assert_eq!(contract.get_owner(), charlie());
+            assert_eq!(contract.get_admins(), [frank(), bob(), eve()]);
+            assert_eq!(contract.recover_admin(charlie()), Err(Error::EnoughAdmins));
         }
 
         #[ink::test]
-        fn add_admins_works() {
+        fn upgrade_and_migrate_work() {
             let mut contract = Transcipt::new();
+            assert_eq!(contract.get_storage_version(), STORAGE_VERSION);
+            assert!(contract.migrate().is_err());
+
+            //only admins can upgrade and migrate
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.upgrade(Hash::from([1; 32])).is_err());
+            contract.storage_version = 1;
+            assert!(contract.migrate().is_err());
+
+            //version 1 contracts gain an explicit pause flag
+            ink::env::test::set_caller::<Environment>(alice());
+            assert_eq!(contract.paused.get(), None);
+            assert!(!contract.is_paused());
+            assert!(contract.migrate().is_ok());
+            assert_eq!(contract.paused.get(), Some(false));
+            assert_eq!(contract.get_storage_version(), STORAGE_VERSION);
+            assert!(contract.migrate().is_err());
+
+            //unversioned layouts could never run this code and are not migrated
+            contract.storage_version = 0;
+            assert_eq!(contract.migrate(), Err(Error::UnknownStorageVersion));
+            contract.storage_version = STORAGE_VERSION;
+
+            //above a threshold of one upgrades need a proposal
             assert!(contract.add_admins(bob()).is_ok());
-            assert!(contract.add_admins(bob()).is_err());
-            assert_eq!(contract.admins, [alice(), bob()] );
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.add_admins(frank()).is_err());
+            assert!(contract.propose(AdminAction::SetThreshold(2)).is_ok());
+            assert!(contract.upgrade(Hash::from([1; 32])).is_err());
         }
 
         #[ink::test]
-        fn add_classes_works() {
+        fn pause_works() {
             let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(eve()).is_ok());
             assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),alice(), [bob()].to_vec()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),alice(), [bob()].to_vec()).is_err());
-            assert!(contract.add_classes("CS51".to_string(),alice(), [eve()].to_vec()).is_err());
-            assert!(contract.add_classes("CS51".to_string(),eve(), [bob()].to_vec()).is_err());
-            assert_eq!(contract.classes.get("CS50".to_string()),Some((alice(), [bob()].to_vec())));
-            assert!(contract.class_list.contains(&"CS50".to_string()));
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob()].to_vec(), 4, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
             assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.add_classes("CS51".to_string(),alice(), [bob()].to_vec()).is_err());
 
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.pause().is_err());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.pause().is_ok());
+            assert!(contract.is_paused());
+            assert_eq!(contract.pause(), Err(Error::Paused));
+
+            //changes are rejected, reads still work
+            assert_eq!(contract.add_student(frank()), Err(Error::Paused));
+            assert_eq!(contract.enroll_student("CS50".to_string(), term, frank()), Err(Error::Paused));
+            assert_eq!(contract.propose(AdminAction::AddAdmin(frank())), Err(Error::Paused));
+            ink::env::test::set_caller::<Environment>(eve());
+            assert_eq!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 5), Err(Error::Paused));
+            assert!(contract.access_grades("CS50".to_string(), term, bob()).is_ok());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert_eq!(contract.add_accessstudents(bob(), frank()), Err(Error::Paused));
+
+            //with a threshold of two unpausing needs two admins
+            ink::env::test::set_caller::<Environment>(alice());
+            contract.admin_threshold = 2;
+            assert!(contract.unpause().is_err());
+            let unpause = contract.propose(AdminAction::Unpause).unwrap();
+            assert!(contract.is_paused());
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.approve_proposal(unpause).is_ok());
+            assert!(!contract.is_paused());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 5).is_ok());
         }
 
         #[ink::test]
-        fn add_score_works() {
+        fn descriptive_errors_work() {
             let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert_eq!(contract.add_term("Spring 2026".to_string(), 100, 100), Err(Error::InvalidTermDates));
             assert!(contract.add_teacher(eve()).is_ok());
             assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),alice(), [bob()].to_vec()).is_ok());
-            assert!(contract.add_classes("CS51".to_string(),eve(), [bob()].to_vec()).is_ok());
-            assert!(contract.add_score("CS50".to_string(), bob(), 2).is_ok());
-            assert_eq!(contract.grades.get((bob(), "CS50".to_string())),Some([2].to_vec()));
-            assert_eq!(contract.access_grades("CS50".to_string(), bob()).unwrap(),[2].to_vec());
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.add_score("CS50".to_string(), bob(), 3).is_ok());
-            assert_eq!(contract.access_grades("CS50".to_string(), bob()).unwrap(),[2,3].to_vec());
-            assert!(contract.add_score("CS51".to_string(), bob(), 3).is_err());
-        }
+            assert_eq!(contract.add_student(bob()), Err(Error::RoleAlreadyGranted));
+            assert_eq!(contract.remove_teacher(frank()), Err(Error::TeacherNotRegistered));
 
+            //each reason for add_classes to fail has its own error
+            assert_eq!(contract.add_classes("CS50".to_string(), term, frank(), [bob()].to_vec(), 4, None), Err(Error::TeacherNotRegistered));
+            assert_eq!(contract.add_classes("CS50".to_string(), term, eve(), [frank()].to_vec(), 4, None), Err(Error::StudentNotRegistered));
+            assert_eq!(contract.add_classes("CS50".to_string(), 7, eve(), [bob()].to_vec(), 4, None), Err(Error::TermNotFound));
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob()].to_vec(), 4, None).is_ok());
+            assert_eq!(contract.add_classes("CS50".to_string(), term, eve(), [bob()].to_vec(), 4, None), Err(Error::ClassAlreadyExists));
+            assert_eq!(contract.enroll_student("CS50".to_string(), term, bob()), Err(Error::StudentAlreadyEnrolled));
+            assert_eq!(contract.add_component("CS51".to_string(), term, "Homework".to_string(), 100, 10), Err(Error::ClassNotFound));
+            assert_eq!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 101, 10), Err(Error::InvalidWeight));
+            assert_eq!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 0), Err(Error::InvalidMaxPoints));
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 50, 10).is_ok());
+            assert_eq!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 50, 10), Err(Error::ComponentAlreadyExists));
 
-        #[ink::test]
-        fn add_accessstudents_works_1() {
-            let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
-            assert!(contract.add_teacher(eve()).is_ok());
-            assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),alice(), [bob()].to_vec()).is_ok());
-            assert!(contract.add_score("CS50".to_string(), bob(), 2).is_ok());
-            assert_eq!(contract.accessstudents.get(bob()).unwrap(), [bob()].to_vec());
-            assert!(contract.add_accessstudents(bob(), frank()).is_ok());
-            assert_eq!(contract.accessstudents.get(bob()).unwrap(), [bob(), frank()].to_vec());
-            assert!(contract.add_accessstudents(bob(), frank()).is_err());
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.add_accessstudents(bob(), charlie()).is_ok());
+            //scores
+            assert_eq!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 5), Err(Error::NotClassTeacher));
+            ink::env::test::set_caller::<Environment>(eve());
+            assert_eq!(contract.add_score("CS50".to_string(), term, charlie(), "Homework".to_string(), 5), Err(Error::StudentNotEnrolled));
+            assert_eq!(contract.add_score("CS50".to_string(), term, bob(), "Exam".to_string(), 5), Err(Error::ComponentNotFound));
+            assert_eq!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 11), Err(Error::PointsExceedMax));
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 5).is_ok());
+            assert_eq!(contract.amend_score("CS50".to_string(), term, bob(), 1, 6, "typo".to_string()), Err(Error::ScoreNotFound));
+            assert_eq!(contract.amend_score("CS50".to_string(), term, bob(), 0, 6, "".to_string()), Err(Error::MissingReason));
 
-            
+            //access
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.add_accessstudents(bob(), frank()).is_ok());
+            assert_eq!(contract.add_accessstudents(bob(), frank()), Err(Error::DuplicateGrant));
+            assert_eq!(contract.add_scoped_access(bob(), charlie(), AccessScope::Classes([].to_vec()), None), Err(Error::EmptyScope));
+            assert_eq!(contract.remove_accessstudents(bob(), bob()), Err(Error::CannotRevokeOwnAccess));
+            assert_eq!(contract.remove_accessstudents(bob(), charlie()), Err(Error::GrantNotFound));
+            assert_eq!(contract.add_accessstudents_until(bob(), charlie(), 0), Err(Error::InvalidExpiry));
         }
 
         #[ink::test]
-        fn add_accessstudents_works_2() {
+        fn not_found_errors_work() {
             let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
             assert!(contract.add_teacher(eve()).is_ok());
             assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),eve(), [bob()].to_vec()).is_ok());
-            assert_eq!(contract.accessstudents.get(bob()).unwrap(), [bob()].to_vec());
-            assert!(contract.add_accessstudents(bob(), frank()).is_ok());
-            assert_eq!(contract.accessstudents.get(bob()).unwrap(), [bob(), frank()].to_vec());
-            assert!(contract.add_accessstudents(bob(), frank()).is_err());
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_teacher(alice()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.add_accessstudents(bob(), charlie()).is_err());
 
-            
+            //unknown classes
+            let missing = || "CS99".to_string();
+            assert_eq!(contract.remove_classes(missing(), term), Err(Error::ClassNotFound));
+            assert_eq!(contract.enroll_student(missing(), term, bob()), Err(Error::ClassNotFound));
+            assert_eq!(contract.unenroll_student(missing(), term, bob()), Err(Error::ClassNotFound));
+            assert_eq!(contract.change_teacher(missing(), term, eve()), Err(Error::ClassNotFound));
+            assert_eq!(contract.add_component(missing(), term, "Homework".to_string(), 100, 10), Err(Error::ClassNotFound));
+            assert_eq!(contract.set_class_scale(missing(), term, [(0, Grade::F)].to_vec()), Err(Error::ClassNotFound));
+            assert_eq!(contract.compute_final_grades(missing(), term), Err(Error::ClassNotFound));
+            assert_eq!(contract.start_grading(missing(), term), Err(Error::ClassNotFound));
+            assert_eq!(contract.finalize_class(missing(), term), Err(Error::ClassNotFound));
+            assert_eq!(contract.access_grades(missing(), term, bob()), Err(Error::ClassNotFound));
+            assert_eq!(contract.final_percentage(missing(), term, bob()), Err(Error::ClassNotFound));
+            assert_eq!(contract.get_score_history(missing(), term, bob()), Err(Error::ClassNotFound));
+            assert_eq!(contract.get_final_grade(missing(), term, bob()), Err(Error::ClassNotFound));
+            assert_eq!(contract.propose(AdminAction::RemoveClass(missing(), term)), Err(Error::ClassNotFound));
+            ink::env::test::set_caller::<Environment>(eve());
+            assert_eq!(contract.add_score(missing(), term, bob(), "Homework".to_string(), 5), Err(Error::ClassNotFound));
+            assert_eq!(contract.amend_score(missing(), term, bob(), 0, 5, "typo".to_string()), Err(Error::ClassNotFound));
+            ink::env::test::set_caller::<Environment>(bob());
+            assert_eq!(contract.open_appeal(missing(), term, 0, Hash::from([1; 32])), Err(Error::ClassNotFound));
+
+            //unknown terms
+            ink::env::test::set_caller::<Environment>(alice());
+            assert_eq!(contract.set_term_status(7, TermStatus::Active), Err(Error::TermNotFound));
+            assert_eq!(contract.add_classes("CS50".to_string(), 7, eve(), [bob()].to_vec(), 4, None), Err(Error::TermNotFound));
+            assert_eq!(contract.access_term_grades(7, bob()), Err(Error::TermNotFound));
+            assert_eq!(contract.get_gpa(bob(), 7), Err(Error::TermNotFound));
+
+            //unknown students and roles
+            assert_eq!(contract.remove_student(frank()), Err(Error::StudentNotRegistered));
+            assert_eq!(contract.remove_admins(frank()), Err(Error::NotAdmin));
+            assert_eq!(contract.request_access(frank(), charlie(), AccessScope::All, None), Err(Error::StudentNotRegistered));
+            assert_eq!(contract.remove_accessstudents(frank(), charlie()), Err(Error::StudentNotRegistered));
+            assert_eq!(contract.revoke_all_access(frank()), Err(Error::StudentNotRegistered));
+            assert_eq!(contract.propose(AdminAction::RemoveStudent(frank())), Err(Error::StudentNotRegistered));
+            ink::env::test::set_caller::<Environment>(frank());
+            assert_eq!(contract.add_accessstudents(frank(), charlie()), Err(Error::StudentNotRegistered));
+
+            //unknown appeals, requests, proposals and removals
+            ink::env::test::set_caller::<Environment>(alice());
+            assert_eq!(contract.respond_appeal(7, Hash::from([1; 32])), Err(Error::AppealNotFound));
+            assert_eq!(contract.resolve_appeal(7, AppealOutcome::Uphold), Err(Error::AppealNotFound));
+            assert_eq!(contract.get_appeal(7), Err(Error::AppealNotFound));
+            assert_eq!(contract.decide_access_request(7, true), Err(Error::RequestNotFound));
+            assert_eq!(contract.override_access_request(7), Err(Error::RequestNotFound));
+            assert_eq!(contract.get_access_request(7), Err(Error::RequestNotFound));
+            assert_eq!(contract.approve_proposal(7), Err(Error::ProposalNotFound));
+            assert_eq!(contract.get_proposal(7), Err(Error::ProposalNotFound));
+            assert_eq!(contract.confirm_admin_removal(frank()), Err(Error::RemovalNotProposed));
         }
 
         #[ink::test]
-        fn add_access_grades_works() {
+        fn teacher_removal_works() {
             let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
-            assert!(contract.add_student(alice()).is_ok());
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(bob()).is_ok());
             assert!(contract.add_teacher(eve()).is_ok());
-            assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),alice(), [bob()].to_vec()).is_ok());
-            assert!(contract.add_classes("CS51".to_string(),eve(), [alice()].to_vec()).is_ok());
-            assert!(contract.add_classes("CS52".to_string(),eve(), [bob()].to_vec()).is_ok());
-            assert!(contract.add_score("CS50".to_string(), bob(), 2).is_ok());
-            assert_eq!(contract.access_grades("CS50".to_string(), bob()).unwrap(),[2].to_vec());
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_teacher(alice()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert_eq!(contract.access_grades("CS51".to_string(), alice()).unwrap(),[].to_vec());
-            assert!(contract.access_grades("CS52".to_string(), bob()).is_err());
-            
+            assert!(contract.add_student(charlie()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, bob(), [charlie()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), term, bob(), [charlie()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS51".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.add_score("CS51".to_string(), term, charlie(), "Homework".to_string(), 5).is_ok());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.start_grading("CS51".to_string(), term).is_ok());
+            assert!(contract.finalize_class("CS51".to_string(), term).is_ok());
+            ink::env::test::set_caller::<Environment>(charlie());
+            let appeal = contract.open_appeal("CS51".to_string(), term, 0, Hash::from([1; 32])).unwrap();
+            ink::env::test::set_caller::<Environment>(alice());
+            assert_eq!(contract.get_active_classes(bob()), [("CS50".to_string(), term)].to_vec());
+            //removal is blocked while bob teaches an active class
+            assert_eq!(contract.remove_teacher(bob()), Err(Error::TeacherHasClasses));
+            assert_eq!(contract.reassign_classes(bob(), frank()), Err(Error::TeacherNotRegistered));
+            ink::env::test::set_caller::<Environment>(bob());
+            assert_eq!(contract.reassign_classes(bob(), eve()), Err(Error::AccessNotAllowed));
+            ink::env::test::set_caller::<Environment>(alice());
+            assert_eq!(contract.reassign_classes(bob(), eve()), Ok(1));
+            assert!(contract.get_active_classes(bob()).is_empty());
+            assert_eq!(contract.get_active_classes(eve()), [("CS50".to_string(), term)].to_vec());
+            assert!(contract.remove_teacher(bob()).is_ok());
+            //bob keeps no access to the finalized class he taught
+            ink::env::test::set_caller::<Environment>(bob());
+            assert_eq!(contract.add_score("CS51".to_string(), term, charlie(), "Homework".to_string(), 5), Err(Error::TeacherNotRegistered));
+            assert_eq!(contract.add_score("CS50".to_string(), term, charlie(), "Homework".to_string(), 5), Err(Error::NotClassTeacher));
+            assert_eq!(contract.respond_appeal(appeal, Hash::from([2; 32])), Err(Error::TeacherNotRegistered));
+            assert_eq!(contract.get_appeal(appeal), Err(Error::TeacherNotRegistered));
+            assert_eq!(contract.appeals.get(appeal).unwrap().status, AppealStatus::Open);
         }
 
         #[ink::test]
-        fn remove_access_grades_works() {
+        fn archival_and_purge_work() {
             let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
-            assert!(contract.add_student(alice()).is_ok());
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
             assert!(contract.add_teacher(eve()).is_ok());
             assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_classes("CS51".to_string(),eve(), [alice()].to_vec()).is_ok());
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_teacher(alice()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert_eq!(contract.access_grades("CS51".to_string(), alice()).unwrap(),[].to_vec());
-            assert!(contract.remove_accessstudents(alice(), alice()).is_err());
-            assert!(contract.access_grades("CS51".to_string(), alice()).is_ok());
-            
-        } 
+            assert!(contract.add_student(charlie()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob(), charlie()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 4).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, charlie(), "Homework".to_string(), 7).is_ok());
+            ink::env::test::set_caller::<Environment>(bob());
+            let appeal = contract.open_appeal("CS50".to_string(), term, 0, Hash::from([1; 32])).unwrap();
 
-        #[ink::test]
-        fn remove_classes_works() {
-            let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
-            assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_student(eve()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),alice(), [bob()].to_vec()).is_ok());
-            assert!(contract.add_classes("CS51".to_string(),alice(), [eve()].to_vec()).is_ok());
-            assert_eq!(contract.classes.get("CS50".to_string()),Some((alice(), [bob()].to_vec())));
-            assert!(contract.class_list.contains(&"CS50".to_string()));
-            assert!(contract.remove_classes("CS50".to_string()).is_ok());
-            assert!(!contract.class_list.contains(&"CS50".to_string()));
-            assert!(contract.class_list.contains(&"CS51".to_string()));
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.remove_classes("CS51".to_string()).is_err());
+            //archived students keep their records but can not join classes
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.remove_student(bob()).is_ok());
+            assert_eq!(contract.get_student_status(bob()), Some(StudentStatus::Withdrawn));
+            assert_eq!(contract.add_classes("CS51".to_string(), term, eve(), [bob()].to_vec(), 3, None), Err(Error::StudentNotActive));
+            assert!(contract.add_classes("CS51".to_string(), term, eve(), [].to_vec(), 3, None).is_ok());
+            assert_eq!(contract.enroll_student("CS51".to_string(), term, bob()), Err(Error::StudentNotActive));
+            ink::env::test::set_caller::<Environment>(bob());
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()).unwrap(), [homework(4)].to_vec());
+            assert_eq!(contract.set_student_status(charlie(), StudentStatus::Graduated), Err(Error::AccessNotAllowed));
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.set_student_status(charlie(), StudentStatus::Graduated).is_ok());
+            assert_eq!(contract.get_student_status(charlie()), Some(StudentStatus::Graduated));
+            assert_eq!(contract.set_student_status(frank(), StudentStatus::Graduated), Err(Error::StudentNotRegistered));
+
+            //only archived students and classes can be purged
+            assert!(contract.add_student(frank()).is_ok());
+            assert_eq!(contract.propose(AdminAction::PurgeStudent(frank())), Err(Error::NotArchived));
+            assert_eq!(contract.propose(AdminAction::PurgeClass("CS50".to_string(), term)), Err(Error::NotArchived));
+
+            //purging a student erases their grades, appeals and enrollments
+            assert!(contract.propose(AdminAction::PurgeStudent(bob())).is_ok());
+            assert_eq!(contract.get_student_status(bob()), None);
+            assert!(!contract.get_students().contains(&bob()));
+            assert_eq!(contract.grades.get((bob(), "CS50".to_string(), term)), None);
+            assert_eq!(contract.get_appeal(appeal), Err(Error::AppealNotFound));
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).unwrap().students, [charlie()].to_vec());
 
+            //purging a class erases its grades
+            assert!(contract.remove_classes("CS50".to_string(), term).is_ok());
+            assert_eq!(contract.access_grades("CS50".to_string(), term, charlie()).unwrap(), [homework(7)].to_vec());
+            assert!(contract.propose(AdminAction::PurgeClass("CS50".to_string(), term)).is_ok());
+            assert!(!contract.class_list.contains(&("CS50".to_string(), term)));
+            assert_eq!(contract.grades.get((charlie(), "CS50".to_string(), term)), None);
+            assert!(contract.get_transcript(charlie()).unwrap().is_empty());
+
+            let events: Vec<Event> = ink::env::test::recorded_events()
+                .map(|e| <Event as scale::Decode>::decode(&mut &e.data[..]).unwrap())
+                .collect();
+            assert!(events.iter().any(|event| matches!(event, Event::StudentRemoved(e) if e.student == bob())));
+            assert!(events.iter().any(|event| matches!(event, Event::ClassRemoved(e) if e.class_name == "CS50")));
+
+            //above a threshold of one students are only archived through a proposal
+            assert!(contract.add_admins(eve()).is_ok());
+            assert!(contract.propose(AdminAction::SetThreshold(2)).is_ok());
+            assert_eq!(contract.set_student_status(frank(), StudentStatus::Inactive), Err(Error::ProposalRequired));
+            assert!(contract.set_student_status(charlie(), StudentStatus::Active).is_ok());
+            let archival = contract.propose(AdminAction::SetStudentStatus(frank(), StudentStatus::Inactive)).unwrap();
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.approve_proposal(archival).is_ok());
+            assert_eq!(contract.get_student_status(frank()), Some(StudentStatus::Inactive));
         }
 
         #[ink::test]
-        fn enroll_unenroll_student_works() {
+        fn waitlist_works() {
             let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(eve()).is_ok());
             assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_student(eve()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),alice(), [bob()].to_vec()).is_ok());
-            assert_eq!(contract.classes.get("CS50".to_string()),Some((alice(), [bob()].to_vec())));
-            assert!(contract.enroll_student("CS50".to_string(), eve()).is_ok());
-            assert_eq!(contract.classes.get("CS50".to_string()),Some((alice(), [bob(), eve()].to_vec())));
-            assert!(contract.enroll_student("CS50".to_string(), eve()).is_err());
-            assert!(contract.enroll_student("CS50".to_string(), charlie()).is_err());
-            assert!(contract.unenroll_student("CS50".to_string(), eve()).is_ok());
-            assert_eq!(contract.classes.get("CS50".to_string()),Some((alice(), [bob()].to_vec())));
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.enroll_student("CS50".to_string(), eve()).is_err());
+            assert!(contract.add_student(charlie()).is_ok());
+            assert!(contract.add_student(django()).is_ok());
+            assert!(contract.add_student(frank()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob(), charlie()].to_vec(), 3, None).is_ok());
 
+            //the capacity must fit the enrolled students
+            assert_eq!(contract.set_class_capacity("CS50".to_string(), term, Some(1)), Err(Error::InvalidCapacity));
+            assert_eq!(contract.set_class_capacity("CS50".to_string(), term, Some(0)), Err(Error::InvalidCapacity));
+            ink::env::test::set_caller::<Environment>(eve());
+            assert_eq!(contract.set_class_capacity("CS50".to_string(), term, Some(2)), Err(Error::AccessNotAllowed));
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.set_class_capacity("CS50".to_string(), term, Some(2)).is_ok());
+
+            //enrollments beyond the capacity go to the waitlist in order
+            assert!(contract.enroll_student("CS50".to_string(), term, django()).is_ok());
+            assert!(contract.enroll_student("CS50".to_string(), term, frank()).is_ok());
+            assert_eq!(contract.enroll_student("CS50".to_string(), term, frank()), Err(Error::StudentAlreadyWaitlisted));
+            assert_eq!(contract.get_seats("CS50".to_string(), term), Ok(SeatCount { enrolled: 2, capacity: Some(2), waitlisted: 2 }));
+            assert_eq!(contract.get_waitlist("CS50".to_string(), term), [django(), frank()].to_vec());
+            assert_eq!(contract.get_waitlist_position("CS50".to_string(), term, frank()), Some(2));
+            assert_eq!(contract.get_waitlist_position("CS50".to_string(), term, bob()), None);
+
+            //a free seat goes to the first student on the waitlist
+            assert!(contract.unenroll_student("CS50".to_string(), term, bob()).is_ok());
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).unwrap().students, [charlie(), django()].to_vec());
+            assert_eq!(contract.get_waitlist_position("CS50".to_string(), term, frank()), Some(1));
+
+            //waitlisted students can leave the waitlist
+            assert!(contract.unenroll_student("CS50".to_string(), term, frank()).is_ok());
+            assert!(contract.get_waitlist("CS50".to_string(), term).is_empty());
+            assert!(contract.enroll_student("CS50".to_string(), term, frank()).is_ok());
+            assert_eq!(contract.student_waitlists.get(frank()).unwrap(), [("CS50".to_string(), term)].to_vec());
+
+            //purging a waitlisted student takes them off the waitlist
+            assert!(contract.enroll_student("CS50".to_string(), term, bob()).is_ok());
+            assert!(contract.remove_student(bob()).is_ok());
+            assert!(contract.propose(AdminAction::PurgeStudent(bob())).is_ok());
+            assert_eq!(contract.get_waitlist("CS50".to_string(), term), [frank()].to_vec());
+            assert_eq!(contract.student_waitlists.get(bob()), None);
+
+            //raising the capacity fills the new seats
+            assert!(contract.set_class_capacity("CS50".to_string(), term, None).is_ok());
+            assert_eq!(contract.get_seats("CS50".to_string(), term), Ok(SeatCount { enrolled: 3, capacity: None, waitlisted: 0 }));
+            assert!(contract.student_waitlists.get(frank()).unwrap().is_empty());
+
+            //classes can start with a capacity, students beyond it are waitlisted
+            assert_eq!(contract.add_classes("CS51".to_string(), term, eve(), [charlie()].to_vec(), 3, Some(0)), Err(Error::InvalidCapacity));
+            assert!(contract.add_classes("CS51".to_string(), term, eve(), [charlie(), django(), frank()].to_vec(), 3, Some(1)).is_ok());
+            assert_eq!(contract.get_seats("CS51".to_string(), term), Ok(SeatCount { enrolled: 1, capacity: Some(1), waitlisted: 2 }));
+            assert_eq!(contract.get_waitlist("CS51".to_string(), term), [django(), frank()].to_vec());
+            assert_eq!(contract.student_waitlists.get(django()).unwrap(), [("CS51".to_string(), term)].to_vec());
+            assert_eq!(contract.add_classes("CS52".to_string(), term, eve(), [django(), django()].to_vec(), 3, Some(1)), Err(Error::StudentAlreadyEnrolled));
+            assert_eq!(contract.get_seats("CS52".to_string(), term), Err(Error::ClassNotFound));
+
+            //archived students keep their seat, purging them gives it to the waitlist
+            assert!(contract.remove_student(charlie()).is_ok());
+            assert_eq!(contract.get_seats("CS51".to_string(), term), Ok(SeatCount { enrolled: 1, capacity: Some(1), waitlisted: 2 }));
+            assert!(contract.propose(AdminAction::PurgeStudent(charlie())).is_ok());
+            assert_eq!(contract.classes.get(("CS51".to_string(), term)).unwrap().students, [django()].to_vec());
+            assert_eq!(contract.get_waitlist("CS51".to_string(), term), [frank()].to_vec());
+            assert_eq!(contract.get_seats("CS99".to_string(), term), Err(Error::ClassNotFound));
+
+            let events: Vec<Event> = ink::env::test::recorded_events()
+                .map(|e| <Event as scale::Decode>::decode(&mut &e.data[..]).unwrap())
+                .collect();
+            assert!(events.iter().any(|event| matches!(event, Event::StudentWaitlisted(e) if e.student == frank() && e.position == 2)));
+            assert!(events.iter().any(|event| matches!(event, Event::StudentEnrolled(e) if e.student == frank())));
+            assert!(events.iter().any(|event| matches!(event, Event::ClassCreated(e) if e.class_name == "CS51" && e.students == [charlie()] && e.capacity == Some(1))));
+            assert!(events.iter().any(|event| matches!(event, Event::StudentWaitlisted(e) if e.class_name == "CS51" && e.student == frank() && e.position == 2)));
         }
 
         #[ink::test]
-        fn change_teacher_works() {
+        fn teaching_assistants_work() {
             let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
             assert!(contract.add_teacher(eve()).is_ok());
             assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),alice(), [bob()].to_vec()).is_ok());
-            assert_eq!(contract.classes.get("CS50".to_string()),Some((alice(), [bob()].to_vec())));
-            assert!(contract.change_teacher("CS50".to_string(), eve()).is_ok());
-            assert_eq!(contract.classes.get("CS50".to_string()),Some((eve(), [bob()].to_vec())));
-            assert!(contract.change_teacher("CS50".to_string(), charlie()).is_err());
-            assert!(contract.change_teacher("CS50".to_string(), bob()).is_err());
+            assert!(contract.add_classes("CS50".to_string(), term, eve(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_classes("CS51".to_string(), term, eve(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
 
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.change_teacher("CS50".to_string(), alice()).is_err());
+            //teachers grant the role and assign their assistants to classes
+            ink::env::test::set_caller::<Environment>(eve());
+            assert_eq!(contract.add_class_assistant("CS50".to_string(), term, charlie()), Err(Error::RoleNotHeld));
+            assert!(contract.grant_role(Role::TeachingAssistant, charlie()).is_ok());
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert_eq!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 6), Err(Error::NotClassTeacher));
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()), Err(Error::AccessNotAllowed));
+            assert_eq!(contract.add_class_assistant("CS50".to_string(), term, charlie()), Err(Error::NotClassTeacher));
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.add_class_assistant("CS50".to_string(), term, charlie()).is_ok());
+            assert_eq!(contract.add_class_assistant("CS50".to_string(), term, charlie()), Err(Error::AssistantAlreadyAdded));
+            assert_eq!(contract.get_class_assistants("CS50".to_string(), term), [charlie()].to_vec());
+
+            //assistants record scores and read grades of their classes only
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 6).is_ok());
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()).unwrap(), [homework(6)].to_vec());
+            assert_eq!(contract.access_grades("CS51".to_string(), term, bob()), Err(Error::AccessNotAllowed));
+            assert!(contract.start_grading("CS50".to_string(), term).is_err());
 
+            //assistants lose access when taken off the class
+            ink::env::test::set_caller::<Environment>(eve());
+            assert!(contract.remove_class_assistant("CS50".to_string(), term, charlie()).is_ok());
+            assert_eq!(contract.remove_class_assistant("CS50".to_string(), term, charlie()), Err(Error::AssistantNotFound));
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert_eq!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 7), Err(Error::NotClassTeacher));
+            assert_eq!(contract.access_grades("CS50".to_string(), term, bob()), Err(Error::AccessNotAllowed));
         }
 
         #[ink::test]
-        fn remove_teacher_works() {
-            
+        fn role_sets_work() {
             let mut contract = Transcipt::new();
             assert!(contract.add_teacher(bob()).is_ok());
             assert!(contract.add_teacher(charlie()).is_ok());
-            assert_eq!(contract.teachers, [bob(), charlie()] );
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert_eq!(contract.member_count(Role::Teacher), 3);
+            assert!(contract.remove_teacher(bob()).is_ok());
+            assert_eq!(contract.get_teachers(), [eve(), charlie()] );
+            assert_eq!(contract.member_count(Role::Teacher), 2);
+            assert!(!contract.is_member(Role::Teacher, bob()));
+            assert!(contract.remove_teacher(bob()).is_err());
             assert!(contract.remove_teacher(charlie()).is_ok());
-            assert_eq!(contract.teachers, [bob()] );
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.remove_teacher(charlie()).is_err());
-            
-            
+            assert!(contract.add_teacher(bob()).is_ok());
+            assert_eq!(contract.get_teachers(), [eve(), bob()] );
+            assert!(contract.is_member(Role::Teacher, bob()));
+            assert!(!contract.is_member(Role::Student, bob()));
         }
 
         #[ink::test]
-        fn remove_student_works() {
+        fn grant_revoke_role_works() {
             let mut contract = Transcipt::new();
-            assert!(contract.add_teacher(alice()).is_ok());
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.grant_role(Role::Registrar, bob()).is_ok());
+            assert!(contract.grant_role(Role::Registrar, bob()).is_err());
+            assert!(contract.has_role(Role::Registrar, bob()));
+            assert!(contract.grant_role(Role::Auditor, charlie()).is_ok());
+            assert_eq!(contract.get_role_members(Role::Auditor), [charlie()] );
+
+            //registrars manage students, teachers and classes but not admins or auditors
+            ink::env::test::set_caller::<Environment>(bob());
             assert!(contract.add_student(eve()).is_ok());
-            assert!(contract.add_student(bob()).is_ok());
-            assert!(contract.add_classes("CS50".to_string(),alice(), [bob(), eve()].to_vec()).is_ok());
-            assert_eq!(contract.classes.get("CS50".to_string()),Some((alice(), [bob(), eve()].to_vec())));
-          
-            assert!(contract.add_score("CS50".to_string(), bob(), 2).is_ok());
-            assert!(contract.add_score("CS50".to_string(), eve(), 3).is_ok());
-            assert_eq!(contract.grades.get((bob(), "CS50".to_string())),Some([2].to_vec()));
-            assert_eq!(contract.grades.get((eve(), "CS50".to_string())),Some([3].to_vec()));
+            assert!(contract.add_teacher(frank()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, frank(), [eve()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_admins(bob()).is_err());
+            assert!(contract.grant_role(Role::Auditor, eve()).is_err());
+            assert!(contract.revoke_role(Role::Auditor, charlie()).is_err());
 
-            assert!(contract.remove_student(bob()).is_ok());
-            assert_eq!(contract.classes.get("CS50".to_string()),Some((alice(), [eve()].to_vec())));
-            assert_eq!(contract.grades.get((bob(), "CS50".to_string())), None);
-            assert_eq!(contract.grades.get((eve(), "CS50".to_string())),Some([3].to_vec()));
-            assert!(!contract.students.contains(&bob()));
+            //auditors can read grades but not change anything
+            ink::env::test::set_caller::<Environment>(charlie());
+            assert!(contract.access_grades("CS50".to_string(), term, eve()).is_ok());
+            assert!(contract.enroll_student("CS50".to_string(), term, eve()).is_err());
 
-                        
+            //students keep their role, removing them archives them and keeps their enrollment
+            ink::env::test::set_caller::<Environment>(bob());
+            assert_eq!(contract.revoke_role(Role::Student, eve()), Err(Error::StudentRoleNotRevocable));
+            assert!(contract.remove_student(eve()).is_ok());
+            assert_eq!(contract.remove_student(eve()), Err(Error::StudentNotActive));
+            assert!(contract.has_role(Role::Student, eve()));
+            assert_eq!(contract.get_student_status(eve()), Some(StudentStatus::Withdrawn));
+            assert_eq!(contract.classes.get(("CS50".to_string(), term)).map(|class| (class.teacher, class.students)),Some((frank(), [eve()].to_vec())));
 
-            assert!(contract.add_admins(charlie()).is_ok());
-            assert!(contract.remove_admins(alice()).is_ok());
-            assert!(contract.remove_student(eve()).is_err());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.revoke_role(Role::Admin, alice()).is_err());
+            assert!(contract.revoke_role(Role::Registrar, bob()).is_ok());
+            assert!(!contract.has_role(Role::Registrar, bob()));
+        }
+
+        #[ink::test]
+        fn role_admin_hierarchy_works() {
+            let mut contract = Transcipt::new();
+            assert_eq!(contract.get_role_admin(Role::Student), Role::Registrar);
+            assert_eq!(contract.get_role_admin(Role::TeachingAssistant), Role::Teacher);
+            assert!(contract.add_teacher(bob()).is_ok());
+
+            //teachers grant teaching assistants
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.grant_role(Role::TeachingAssistant, charlie()).is_ok());
+            assert!(contract.grant_role(Role::Student, eve()).is_err());
+            assert!(contract.set_role_admin(Role::Student, Role::Teacher).is_err());
 
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.set_role_admin(Role::Student, Role::Teacher).is_ok());
+            assert!(contract.set_role_admin(Role::Admin, Role::Teacher).is_err());
+            assert_eq!(contract.get_role_admin(Role::Student), Role::Teacher);
+
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.grant_role(Role::Student, eve()).is_ok());
+            assert!(contract.has_role(Role::Student, eve()));
         }
 
+        #[ink::test]
+        fn events_are_emitted() {
+            let mut contract = Transcipt::new();
+            let term = contract.add_term("Fall 2025".to_string(), 0, 100).unwrap();
+            assert!(contract.add_teacher(alice()).is_ok());
+            assert!(contract.add_teacher(eve()).is_ok());
+            assert!(contract.add_student(bob()).is_ok());
+            assert!(contract.add_student(charlie()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_ok());
+            assert!(contract.add_component("CS50".to_string(), term, "Homework".to_string(), 100, 10).is_ok());
+            assert!(contract.add_score("CS50".to_string(), term, bob(), "Homework".to_string(), 2).is_ok());
+            ink::env::test::set_caller::<Environment>(bob());
+            assert!(contract.add_accessstudents(bob(), frank()).is_ok());
+            ink::env::test::set_caller::<Environment>(alice());
+            assert!(contract.remove_accessstudents(bob(), frank()).is_ok());
+            assert!(contract.enroll_student("CS50".to_string(), term, charlie()).is_ok());
+            assert!(contract.unenroll_student("CS50".to_string(), term, charlie()).is_ok());
+            assert!(contract.change_teacher("CS50".to_string(), term, eve()).is_ok());
+            assert!(contract.add_classes("CS50".to_string(), term, alice(), [bob()].to_vec(), 3, None).is_err());
 
+            let events: Vec<Event> = ink::env::test::recorded_events()
+                .map(|e| <Event as scale::Decode>::decode(&mut &e.data[..]).unwrap())
+                .collect();
+            assert_eq!(events.iter().filter(|event| matches!(event, Event::ClassCreated(_))).count(), 1);
+            assert!(events.iter().any(|event| matches!(event, Event::TermCreated(e) if e.term_id == term)));
+            assert!(events.iter().any(|event| matches!(event, Event::TeacherAdded(e) if e.teacher == alice())));
+            assert!(events.iter().any(|event| matches!(event, Event::ClassCreated(e) if e.class_name == "CS50" && e.teacher == alice() && e.students == [bob()])));
+            assert!(events.iter().any(|event| matches!(event, Event::ScoreRecorded(e) if e.student == bob() && e.component == "Homework" && e.points == 2)));
+            assert!(events.iter().any(|event| matches!(event, Event::AccessGranted(e) if e.student == bob() && e.grantee == frank())));
+            assert!(events.iter().any(|event| matches!(event, Event::AccessRevoked(e) if e.student == bob() && e.grantee == frank())));
+            assert!(events.iter().any(|event| matches!(event, Event::StudentEnrolled(e) if e.student == charlie())));
+            assert!(events.iter().any(|event| matches!(event, Event::StudentUnenrolled(e) if e.student == charlie())));
+            assert!(events.iter().any(|event| matches!(event, Event::TeacherChanged(e) if e.old_teacher == alice() && e.new_teacher == eve())));
+        }
 
-// remove student
 
 
 